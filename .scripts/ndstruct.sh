@@ -5,6 +5,11 @@
 $rt test-generic ndstruct
 $rt test-with-features ndstruct alloc
 $rt test-with-features ndstruct std
+$rt test-with-features ndstruct arrow
+$rt test-with-features ndstruct complex
+$rt test-with-features ndstruct graph
+$rt test-with-features ndstruct libm
 $rt test-with-features ndstruct rand
 $rt test-with-features ndstruct rayon
 $rt test-with-features ndstruct serde
+$rt test-with-features ndstruct simd
@@ -2,14 +2,52 @@
 
 #![no_main]
 
-use arbitrary as _;
 use rayon as _;
 
 use libfuzzer_sys::fuzz_target;
-use ndstruct::coo::CooVec;
+use ndstruct::csl::{CslRndBuilder, CslVec};
 use rand::rngs::mock::StepRng;
 
-fuzz_target!(|values: ([usize; 2], usize)| {
-  let (dims, nnz) = values;
-  let _rslt = CooVec::new_controlled_random_rand(dims, nnz, &mut StepRng::new(0, 0), |_, _| 0);
+#[derive(Debug, arbitrary::Arbitrary)]
+enum LineNnz {
+  ErdosRenyi(u8),
+  Fixed(usize),
+  PowerLaw(usize, u8),
+  Uniform(usize),
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Pattern {
+  Any,
+  Banded(usize, usize),
+  BlockDiagonal(usize),
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Values {
+  dims: [usize; 2],
+  line_nnz: LineNnz,
+  pattern: Pattern,
+  step: u64,
+  symmetric: bool,
+}
+
+fuzz_target!(|values: Values| {
+  let mut builder = CslRndBuilder::new(values.dims.map(|dim| dim % 128));
+  builder = match values.line_nnz {
+    LineNnz::ErdosRenyi(probability) => builder.erdos_renyi(f64::from(probability) / 255.0),
+    LineNnz::Fixed(line_nnz) => builder.fixed_line_nnz(line_nnz),
+    LineNnz::PowerLaw(nnz, exponent) => builder.power_law(nnz, exponent.into()),
+    LineNnz::Uniform(nnz) => builder.nnz(nnz),
+  };
+  builder = match values.pattern {
+    Pattern::Any => builder,
+    Pattern::Banded(lower, upper) => builder.banded(lower, upper),
+    Pattern::BlockDiagonal(block) => builder.block_diagonal(block),
+  };
+  if values.symmetric {
+    builder = builder.symmetric();
+  }
+  let mut rng = StepRng::new(0, values.step);
+  let _rslt: ndstruct::Result<CslVec<i32, 2>> = builder.build(&mut rng, |_, _| 0);
 });
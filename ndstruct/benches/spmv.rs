@@ -0,0 +1,83 @@
+//! Sparse matrix-vector multiplications of a matrix whose rows follow a power-law distribution.
+//!
+//! Run with `cargo bench --all-features --bench spmv` and compare the parallel kernels against
+//! the sequential baseline.
+
+#![allow(
+  clippy::tests_outside_test_module,
+  clippy::unwrap_used,
+  reason = "Benchmarks are expected to panic on invalid setups"
+)]
+#![feature(test)]
+
+extern crate test;
+
+use ndstruct::csl::{CslRndBuilder, CslVec};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use rayon::prelude::*;
+use test::Bencher;
+
+const COLS: usize = 100_000;
+const NNZ: usize = 1_000_000;
+const ROWS: usize = 20_000;
+
+fn skewed_matrix() -> (CslVec<f64, 2>, Vec<f64>) {
+  let mut rng = SmallRng::seed_from_u64(0);
+  let csl = CslRndBuilder::new([ROWS, COLS])
+    .power_law(NNZ, 1)
+    .build(&mut rng, |r, _| r.gen_range(0.0..1.0))
+    .unwrap();
+  let x = (0..COLS).map(|_| rng.gen_range(0.0..1.0)).collect();
+  (csl, x)
+}
+
+#[bench]
+fn spmv(b: &mut Bencher) {
+  let (csl, x) = skewed_matrix();
+  let mut y = vec![0.0; ROWS];
+  b.iter(|| csl.spmv(&x, &mut y).unwrap());
+}
+
+#[bench]
+fn spmv_par_merge_path(b: &mut Bencher) {
+  let (csl, x) = skewed_matrix();
+  let mut y = vec![0.0; ROWS];
+  b.iter(|| csl.par_spmv(&x, &mut y).unwrap());
+}
+
+#[bench]
+fn spmv_par_nnz_balanced_chunks(b: &mut Bencher) {
+  let (csl, x) = skewed_matrix();
+  let mut y = vec![0.0; ROWS];
+  let chunks = rayon::current_num_threads().saturating_mul(4);
+  b.iter(|| {
+    let mut y_chunks = Vec::new();
+    let mut y_rest = y.as_mut_slice();
+    for chunk in csl.par_nnz_balanced_chunks(chunks).unwrap().collect::<Vec<_>>() {
+      let (y_head, y_tail) = y_rest.split_at_mut(chunk.dims()[0]);
+      y_chunks.push((chunk, y_head));
+      y_rest = y_tail;
+    }
+    y_chunks.into_par_iter().filter(|(chunk, _)| chunk.dims()[0] > 0).for_each(
+      |(chunk, y_chunk)| {
+        chunk.spmv(&x, y_chunk).unwrap();
+      },
+    );
+  });
+}
+
+#[bench]
+fn spmv_par_outermost_lines(b: &mut Bencher) {
+  let (csl, x) = skewed_matrix();
+  let mut y = vec![0.0; ROWS];
+  b.iter(|| {
+    csl.outermost_line_rayon_iter().unwrap().zip(y.par_iter_mut()).for_each(|(row, y_elem)| {
+      *y_elem = row
+        .data()
+        .iter()
+        .zip(row.indcs())
+        .map(|(value, idx)| value * x.get(*idx).copied().unwrap_or_default())
+        .sum();
+    });
+  });
+}
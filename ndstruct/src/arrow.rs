@@ -0,0 +1,444 @@
+//! Apache Arrow
+//!
+//! Columnar representations of sparse structures that can be exchanged through record batches
+//! or written to and read from Arrow IPC files.
+//!
+//! * [`Coo`]: One `UInt64` column per axis named `axis_0`, `axis_1`, ..., followed by a column
+//!   named `values`.
+//! * 2-D [`Csl`]: A single column named `lines` of type `List<Struct<index: UInt64, value>>`
+//!   where each list is a line.
+//!
+//! In both cases, the dimensions are stored in the schema metadata under the `ndstruct.dims`
+//! key as comma-separated values. Imported instances are validated by [`Coo::new_located`] or
+//! [`Csl::new_located`], which means that errors contain the location of offending elements.
+
+mod arrow_error;
+
+use crate::{
+  coo::{Coo, CooVec},
+  csl::{Csl, CslVec},
+};
+use alloc::{
+  format,
+  string::{String, ToString},
+  sync::Arc,
+  vec::Vec,
+};
+use arrow_array::{
+  cast::AsArray,
+  types::{
+    Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type,
+    UInt64Type, UInt8Type,
+  },
+  Array, ArrayRef, ArrowPrimitiveType, ListArray, PrimitiveArray, RecordBatch, StructArray,
+  UInt64Array,
+};
+use arrow_buffer::OffsetBuffer;
+pub use arrow_error::*;
+use arrow_ipc::{reader::FileReader, writer::FileWriter};
+use arrow_schema::{DataType, Field, Fields, Schema};
+use cl_aux::SingleTypeStorage;
+use std::{
+  collections::HashMap,
+  io::{Read, Seek, Write},
+};
+
+const DIMS_KEY: &str = "ndstruct.dims";
+const INDEX: &str = "index";
+const LINES: &str = "lines";
+const VALUE: &str = "value";
+const VALUES: &str = "values";
+
+/// Elements that can be stored in Arrow primitive arrays.
+pub trait ArrowElement: Copy {
+  /// Arrow type whose native representation is `Self`.
+  type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+impl<DATA, DS, const D: usize> Coo<DS, D>
+where
+  DATA: ArrowElement,
+  DS: AsRef<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = ([usize; D], DATA)>,
+{
+  /// Record batch with one `UInt64` column per axis and a column of values.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::coo_array_5;
+  /// let batch = coo_array_5().to_record_batch().unwrap();
+  /// assert_eq!((batch.num_columns(), batch.num_rows()), (6, 7));
+  /// assert_eq!(batch.schema().field(0).name(), "axis_0");
+  /// assert_eq!(batch.schema().field(5).name(), "values");
+  /// ```
+  #[inline]
+  pub fn to_record_batch(&self) -> crate::Result<RecordBatch> {
+    let data = self.data.as_ref();
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(D.saturating_add(1));
+    let mut fields = Vec::with_capacity(D.saturating_add(1));
+    for axis in 0..D {
+      let indcs = data
+        .iter()
+        .map(|(indcs, _)| u64::try_from(*indcs.get(axis)?).ok())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(ArrowError::IntegerOverflow)?;
+      columns.push(Arc::new(UInt64Array::from(indcs)));
+      fields.push(Field::new(format!("axis_{axis}"), DataType::UInt64, false));
+    }
+    columns.push(Arc::new(PrimitiveArray::<DATA::ArrowType>::from_iter_values(
+      data.iter().map(|(_, elem)| *elem),
+    )));
+    fields.push(Field::new(VALUES, DATA::ArrowType::DATA_TYPE, false));
+    let schema = Schema::new_with_metadata(fields, dims_metadata(&self.dims.0));
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+  }
+
+  /// Writes an Arrow IPC file that contains a single [`to_record_batch`](#method.to_record_batch)
+  /// record batch.
+  ///
+  /// # Arguments
+  ///
+  /// * `writer`: Destination of the file
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooVec, doc_tests::coo_array_5};
+  /// let coo = coo_array_5();
+  /// let mut file = Vec::new();
+  /// coo.write_ipc_file(&mut file).unwrap();
+  /// let read = CooVec::<i32, 5>::read_ipc_file(std::io::Cursor::new(file)).unwrap();
+  /// assert_eq!(read.data(), coo.data());
+  /// ```
+  #[inline]
+  pub fn write_ipc_file<W>(&self, writer: W) -> crate::Result<()>
+  where
+    W: Write,
+  {
+    write_ipc_file(&self.to_record_batch()?, writer)
+  }
+}
+
+impl<DATA, const D: usize> CooVec<DATA, D>
+where
+  DATA: ArrowElement,
+{
+  /// Imports a record batch with the layout of [`Coo::to_record_batch`].
+  ///
+  /// # Arguments
+  ///
+  /// * `batch`: Record batch
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooVec, doc_tests::coo_array_5};
+  /// let coo = coo_array_5();
+  /// let imported = CooVec::<i32, 5>::from_record_batch(&coo.to_record_batch().unwrap()).unwrap();
+  /// assert_eq!((imported.dims(), imported.data()), (coo.dims(), &coo.data()[..]));
+  /// ```
+  #[inline]
+  pub fn from_record_batch(batch: &RecordBatch) -> crate::Result<Self> {
+    let dims = dims_from_metadata(batch.schema().metadata())?;
+    let mut data = Vec::with_capacity(batch.num_rows());
+    push_coo_batch(batch, &mut data)?;
+    Coo::new_located(dims, data)
+  }
+
+  /// Imports all record batches of an Arrow IPC file written by
+  /// [`Coo::write_ipc_file`] or by any other producer that uses the same layout.
+  ///
+  /// # Arguments
+  ///
+  /// * `reader`: Source of the file
+  ///
+  /// # Example
+  ///
+  /// See [`Coo::write_ipc_file`].
+  #[inline]
+  pub fn read_ipc_file<R>(reader: R) -> crate::Result<Self>
+  where
+    R: Read + Seek,
+  {
+    let file_reader = FileReader::try_new(reader, None)?;
+    let dims = dims_from_metadata(file_reader.schema().metadata())?;
+    let mut data = Vec::new();
+    for batch in file_reader {
+      push_coo_batch(&batch?, &mut data)?;
+    }
+    Coo::new_located(dims, data)
+  }
+}
+
+impl<DATA, DS, IS, OS> Csl<DS, IS, OS, 2>
+where
+  DATA: ArrowElement,
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Record batch with a single `List<Struct<index: UInt64, value>>` column where each list is
+  /// a line.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use arrow_array::cast::AsArray;
+  /// use ndstruct::csl::CslArray;
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let batch = csl.to_record_batch().unwrap();
+  /// let lines = batch.column(0).as_list::<i32>();
+  /// assert_eq!(lines.value_offsets(), &[0, 2, 3]);
+  /// ```
+  #[inline]
+  pub fn to_record_batch(&self) -> crate::Result<RecordBatch> {
+    let (data, indcs, offs) = (self.data.as_ref(), self.indcs.as_ref(), self.offs.as_ref());
+    let first_off = offs.first().copied().unwrap_or(0);
+    let mut list_offs = offs
+      .iter()
+      .map(|off| i32::try_from(off.saturating_sub(first_off)).ok())
+      .collect::<Option<Vec<_>>>()
+      .ok_or(ArrowError::IntegerOverflow)?;
+    if list_offs.is_empty() {
+      list_offs.push(0);
+    }
+    let indcs_array = indcs
+      .iter()
+      .map(|idx| u64::try_from(*idx).ok())
+      .collect::<Option<Vec<_>>>()
+      .ok_or(ArrowError::IntegerOverflow)?;
+    let entries_columns: Vec<ArrayRef> = alloc::vec![
+      Arc::new(UInt64Array::from(indcs_array)),
+      Arc::new(PrimitiveArray::<DATA::ArrowType>::from_iter_values(data.iter().copied())),
+    ];
+    let entries = StructArray::try_new(line_fields::<DATA>(), entries_columns, None)?;
+    let item = Arc::new(Field::new_list_field(DataType::Struct(line_fields::<DATA>()), false));
+    let lines = ListArray::try_new(
+      Arc::clone(&item),
+      OffsetBuffer::new(list_offs.into()),
+      Arc::new(entries),
+      None,
+    )?;
+    let schema = Schema::new_with_metadata(
+      alloc::vec![Field::new(LINES, DataType::List(item), false)],
+      dims_metadata(&self.dims.0),
+    );
+    let columns: Vec<ArrayRef> = alloc::vec![Arc::new(lines)];
+    Ok(RecordBatch::try_new(Arc::new(schema), columns)?)
+  }
+
+  /// Writes an Arrow IPC file that contains a single [`to_record_batch`](#method.to_record_batch)
+  /// record batch.
+  ///
+  /// # Arguments
+  ///
+  /// * `writer`: Destination of the file
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, CslVec};
+  /// let csl = CslArray::new([3, 3], [1, 2, 3], [0, 2, 1], [0, 2, 2, 3]).unwrap();
+  /// let mut file = Vec::new();
+  /// csl.write_ipc_file(&mut file).unwrap();
+  /// let read = CslVec::<i32, 2>::read_ipc_file(std::io::Cursor::new(file)).unwrap();
+  /// assert_eq!((read.data(), read.indcs(), read.offs()), (csl.data(), csl.indcs(), csl.offs()));
+  /// ```
+  #[inline]
+  pub fn write_ipc_file<W>(&self, writer: W) -> crate::Result<()>
+  where
+    W: Write,
+  {
+    write_ipc_file(&self.to_record_batch()?, writer)
+  }
+}
+
+impl<DATA> CslVec<DATA, 2>
+where
+  DATA: ArrowElement,
+{
+  /// Imports a record batch with the layout of [`Csl::to_record_batch`].
+  ///
+  /// # Arguments
+  ///
+  /// * `batch`: Record batch
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, CslVec};
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let imported = CslVec::<i32, 2>::from_record_batch(&csl.to_record_batch().unwrap()).unwrap();
+  /// assert_eq!(imported.value([0, 2]), Some(&2));
+  /// assert_eq!(imported.value([1, 1]), Some(&3));
+  /// ```
+  #[inline]
+  pub fn from_record_batch(batch: &RecordBatch) -> crate::Result<Self> {
+    let dims = dims_from_metadata(batch.schema().metadata())?;
+    let mut csl = Self::default();
+    csl.offs.push(0);
+    push_csl_batch(batch, &mut csl)?;
+    Csl::new_located(dims, csl.data, csl.indcs, csl.offs)
+  }
+
+  /// Imports all record batches of an Arrow IPC file written by [`Csl::write_ipc_file`] or by
+  /// any other producer that uses the same layout. Lines of subsequent batches are appended
+  /// after the lines of previous batches.
+  ///
+  /// # Arguments
+  ///
+  /// * `reader`: Source of the file
+  ///
+  /// # Example
+  ///
+  /// See [`Csl::write_ipc_file`].
+  #[inline]
+  pub fn read_ipc_file<R>(reader: R) -> crate::Result<Self>
+  where
+    R: Read + Seek,
+  {
+    let file_reader = FileReader::try_new(reader, None)?;
+    let dims = dims_from_metadata(file_reader.schema().metadata())?;
+    let mut csl = Self::default();
+    csl.offs.push(0);
+    for batch in file_reader {
+      push_csl_batch(&batch?, &mut csl)?;
+    }
+    Csl::new_located(dims, csl.data, csl.indcs, csl.offs)
+  }
+}
+
+impl From<arrow_schema::ArrowError> for crate::Error {
+  #[inline]
+  fn from(from: arrow_schema::ArrowError) -> Self {
+    Self::Arrow(ArrowError::Upstream(from.to_string()))
+  }
+}
+
+fn dims_from_metadata<const D: usize>(
+  metadata: &HashMap<String, String>,
+) -> crate::Result<[usize; D]> {
+  let value = metadata.get(DIMS_KEY).ok_or(ArrowError::InvalidDims)?;
+  let mut iter = value.split(',').filter(|elem| !elem.is_empty());
+  let mut dims = [0; D];
+  for dim in &mut dims {
+    *dim = iter.next().and_then(|elem| elem.trim().parse().ok()).ok_or(ArrowError::InvalidDims)?;
+  }
+  if iter.next().is_some() {
+    return Err(ArrowError::InvalidDims.into());
+  }
+  Ok(dims)
+}
+
+fn dims_metadata<const D: usize>(dims: &[usize; D]) -> HashMap<String, String> {
+  let value = dims.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+  HashMap::from([(DIMS_KEY.into(), value)])
+}
+
+fn line_fields<DATA>() -> Fields
+where
+  DATA: ArrowElement,
+{
+  Fields::from(alloc::vec![
+    Field::new(INDEX, DataType::UInt64, false),
+    Field::new(VALUE, DATA::ArrowType::DATA_TYPE, false),
+  ])
+}
+
+fn primitive_values<T>(array: Option<&ArrayRef>) -> crate::Result<&[T::Native]>
+where
+  T: ArrowPrimitiveType,
+{
+  let primitive =
+    array.and_then(|elem| elem.as_primitive_opt::<T>()).ok_or(ArrowError::InvalidColumn)?;
+  if primitive.null_count() != 0 {
+    return Err(ArrowError::InvalidColumn.into());
+  }
+  Ok(primitive.values())
+}
+
+fn push_coo_batch<DATA, const D: usize>(
+  batch: &RecordBatch,
+  data: &mut Vec<([usize; D], DATA)>,
+) -> crate::Result<()>
+where
+  DATA: ArrowElement,
+{
+  let mut axes = Vec::with_capacity(D);
+  for axis in 0..D {
+    axes.push(primitive_values::<UInt64Type>(batch.column_by_name(&format!("axis_{axis}")))?);
+  }
+  let values = primitive_values::<DATA::ArrowType>(batch.column_by_name(VALUES))?;
+  for (row, elem) in values.iter().enumerate() {
+    let mut indcs = [0; D];
+    for (idx, axis) in indcs.iter_mut().zip(&axes) {
+      let value = axis.get(row).copied().ok_or(ArrowError::InvalidColumn)?;
+      *idx = usize::try_from(value).map_err(|_err| ArrowError::IntegerOverflow)?;
+    }
+    data.push((indcs, *elem));
+  }
+  Ok(())
+}
+
+fn push_csl_batch<DATA>(batch: &RecordBatch, csl: &mut CslVec<DATA, 2>) -> crate::Result<()>
+where
+  DATA: ArrowElement,
+{
+  let lines = batch
+    .column_by_name(LINES)
+    .and_then(|elem| elem.as_list_opt::<i32>())
+    .ok_or(ArrowError::InvalidColumn)?;
+  let entries = lines.values().as_struct_opt().ok_or(ArrowError::InvalidColumn)?;
+  if lines.null_count() != 0 || entries.null_count() != 0 {
+    return Err(ArrowError::InvalidColumn.into());
+  }
+  let indcs = primitive_values::<UInt64Type>(entries.column_by_name(INDEX))?;
+  let values = primitive_values::<DATA::ArrowType>(entries.column_by_name(VALUE))?;
+  let list_offs = lines.value_offsets();
+  let to_usize = |off: &i32| usize::try_from(*off).map_err(|_err| ArrowError::IntegerOverflow);
+  let first_off = list_offs.first().map(to_usize).transpose()?.unwrap_or(0);
+  let last_off = list_offs.last().map(to_usize).transpose()?.unwrap_or(0);
+  let range = first_off..last_off;
+  let base = csl.data.len();
+  for idx in indcs.get(range.clone()).ok_or(ArrowError::InvalidColumn)? {
+    csl.indcs.push(usize::try_from(*idx).map_err(|_err| ArrowError::IntegerOverflow)?);
+  }
+  csl.data.extend(values.get(range).ok_or(ArrowError::InvalidColumn)?.iter().copied());
+  for off in list_offs.get(1..).unwrap_or_default() {
+    csl.offs.push(base.saturating_add(to_usize(off)?.saturating_sub(first_off)));
+  }
+  Ok(())
+}
+
+fn write_ipc_file<W>(batch: &RecordBatch, writer: W) -> crate::Result<()>
+where
+  W: Write,
+{
+  let mut file_writer = FileWriter::try_new(writer, &batch.schema())?;
+  file_writer.write(batch)?;
+  file_writer.finish()?;
+  Ok(())
+}
+
+macro_rules! impl_arrow_element {
+  ($($ty:ty => $arrow_ty:ty),*) => {
+    $(
+      impl ArrowElement for $ty {
+        type ArrowType = $arrow_ty;
+      }
+    )*
+  };
+}
+
+impl_arrow_element!(
+  f32 => Float32Type,
+  f64 => Float64Type,
+  i8 => Int8Type,
+  i16 => Int16Type,
+  i32 => Int32Type,
+  i64 => Int64Type,
+  u8 => UInt8Type,
+  u16 => UInt16Type,
+  u32 => UInt32Type,
+  u64 => UInt64Type
+);
@@ -0,0 +1,68 @@
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter};
+
+/// Any error related to Apache Arrow conversions
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ArrowError {
+  /// A stored index or offset doesn't fit into the target integer type, e.g., a CSL with more
+  /// than `i32::MAX` elements can't be represented by the 32-bit offsets of a `ListArray`
+  ///
+  /// ```rust
+  /// use arrow_array::{RecordBatch, UInt64Array};
+  /// use ndstruct::{arrow::ArrowError, coo::{CooArray, CooError, CooVec}};
+  /// use std::sync::Arc;
+  /// let batch = CooArray::new([10], [([1], 1)]).unwrap().to_record_batch().unwrap();
+  /// let columns = vec![Arc::new(UInt64Array::from(vec![u64::MAX])) as _, batch.column(1).clone()];
+  /// let batch = RecordBatch::try_new(batch.schema(), columns).unwrap();
+  /// let rslt = CooVec::<i32, 1>::from_record_batch(&batch);
+  /// if usize::try_from(u64::MAX).is_err() {
+  ///   // Indices of targets with pointers smaller than 64 bits can't hold every `u64`.
+  ///   assert_eq!(rslt, Err(ndstruct::Error::Arrow(ArrowError::IntegerOverflow)));
+  /// } else {
+  ///   // Otherwise the index is converted and then rejected by the validation of the instance.
+  ///   let err = rslt.unwrap_err();
+  ///   assert_eq!(err.without_location(), ndstruct::Error::Coo(CooError::InvalidIndcs));
+  /// }
+  /// ```
+  IntegerOverflow,
+
+  /// A required column is missing, has an unexpected type or contains null values
+  ///
+  /// ```rust
+  /// use ndstruct::{arrow::ArrowError, coo::CooVec, doc_tests::coo_array_5};
+  /// let batch = coo_array_5().to_record_batch().unwrap();
+  /// let rslt = CooVec::<f64, 5>::from_record_batch(&batch);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Arrow(ArrowError::InvalidColumn)));
+  /// ```
+  InvalidColumn,
+
+  /// The dimensions stored in the schema metadata are missing, malformed or have a different
+  /// number of dimensions
+  ///
+  /// ```rust
+  /// use ndstruct::{arrow::ArrowError, coo::CooVec, doc_tests::coo_array_5};
+  /// let batch = coo_array_5().to_record_batch().unwrap();
+  /// let rslt = CooVec::<i32, 4>::from_record_batch(&batch);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Arrow(ArrowError::InvalidDims)));
+  /// ```
+  InvalidDims,
+
+  /// Error returned by the Arrow implementation, e.g., a malformed IPC file
+  ///
+  /// ```rust
+  /// use ndstruct::{arrow::ArrowError, coo::CooVec};
+  /// let rslt = CooVec::<i32, 2>::read_ipc_file(std::io::Cursor::new(b"Not an IPC file"));
+  /// assert!(matches!(rslt, Err(ndstruct::Error::Arrow(ArrowError::Upstream(_)))));
+  /// ```
+  Upstream(String),
+}
+
+impl Display for ArrowError {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+    Debug::fmt(self, f)
+  }
+}
+
+impl core::error::Error for ArrowError {}
@@ -3,11 +3,16 @@
 mod coo_error;
 mod coo_utils;
 
+use crate::{
+  dense::{Dense, DenseError},
+  utils::{indcs_to_linear_idx, linear_idx_to_indcs, nonzero_dims_product, windows2},
+  Location,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use cl_aux::{ArrayWrapper, SingleTypeStorage};
+use cl_aux::{ArrayWrapper, Push, SingleTypeStorage};
 pub use coo_error::*;
-use coo_utils::{does_not_have_duplicates_sorted, value, value_mut};
+use coo_utils::{value, value_mut};
 
 /// COO backed by a static array.
 pub type CooArray<DATA, const D: usize, const DN: usize> = Coo<[([usize; D], DATA); DN], D>;
@@ -26,6 +31,13 @@ pub type CooVec<DATA, const D: usize> = Coo<Vec<([usize; D], DATA)>, D>;
 /// * `D`: Number of dimensions
 /// * `DS`: Data Storage
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+  feature = "serde",
+  expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "Deserialized instances aren't validated and should be checked with `validate`"
+  )
+)]
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Coo<DS, const D: usize> {
   pub(crate) data: DS,
@@ -70,27 +82,65 @@ where
   /// ```
   #[inline]
   pub fn new(dims: [usize; D], data: DS) -> crate::Result<Self> {
-    if !crate::utils::are_in_ascending_order(data.as_ref(), |a, b| [&a.0, &b.0]) {
-      return Err(CooError::InvalidIndcsOrder.into());
-    }
-    let has_invalid_indcs = !data.as_ref().iter().all(|&(indcs, _)| {
-      indcs.iter().zip(dims.iter()).all(
-        |(data_idx, dim)| {
-          if dim == &0 {
-            true
-          } else {
-            data_idx < dim
-          }
-        },
-      )
-    });
-    if has_invalid_indcs {
-      return Err(CooError::InvalidIndcs.into());
-    }
-    if !does_not_have_duplicates_sorted(data.as_ref(), |a, b| a.0[..] != b.0[..]) {
-      return Err(CooError::DuplicatedIndices.into());
-    }
-    Ok(Self { data, dims: dims.into() })
+    let rslt = Self { data, dims: dims.into() };
+    rslt.validate().map_err(crate::Error::without_location)?;
+    Ok(rslt)
+  }
+
+  /// Same as [`new`](#method.new) but violations related to a single element are returned as
+  /// [`crate::Error::CooAt`], which also contains the [`Location`](crate::Location) of the
+  /// offending element. Useful to debug instances imported from external sources.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `data`: Data collection
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::{CooArray, CooError}, Error};
+  /// let err = CooArray::new_located([10], [([1], 8.0), ([1], 9.0)]).unwrap_err();
+  /// let location = err.location().unwrap();
+  /// assert_eq!((location.pos, location.values), (1, Some([0, 1])));
+  /// assert_eq!(err.without_location(), Error::Coo(CooError::DuplicatedIndices));
+  /// ```
+  #[inline]
+  pub fn new_located(dims: [usize; D], data: DS) -> crate::Result<Self> {
+    let rslt = Self { data, dims: dims.into() };
+    rslt.validate()?;
+    Ok(rslt)
+  }
+
+  /// Creates a COO instance without validating the arguments in release builds.
+  ///
+  /// Useful for hot paths that build instances from parts that are already known to be valid.
+  /// Under `debug_assertions`, the arguments are fully validated and invalid instances panic.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `data`: Data collection
+  ///
+  /// # Safety
+  ///
+  /// The arguments must uphold all the invariants checked by [`validate`](#method.validate),
+  /// otherwise all other methods can return wrong results or panic.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::coo::CooArray;
+  /// // SAFETY: Indices are sorted, unique and within the dimensions
+  /// let coo = unsafe { CooArray::new_unchecked([10], [([0], 8.0), ([5], 9.0)]) };
+  /// assert_eq!(coo.value([5]), Some(&9.0));
+  /// ```
+  #[expect(unsafe_code, reason = "The validation of the arguments is delegated to the caller")]
+  #[inline]
+  pub unsafe fn new_unchecked(dims: [usize; D], data: DS) -> Self {
+    let rslt = Self { data, dims: dims.into() };
+    debug_assert!(rslt.validate().is_ok(), "Invalid COO instance: {:?}", rslt.validate());
+    rslt
   }
 
   /// The data that is being stored.
@@ -106,6 +156,191 @@ where
     self.data.as_ref()
   }
 
+  /// Iterator that returns all non-zero elements in storage order alongside the indices of all
+  /// dimensions.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::coo_array_5;
+  /// let coo = coo_array_5();
+  /// let mut iter = coo.iter_nnz();
+  /// assert_eq!(iter.next(), Some(([0, 0, 1, 1, 2], &1)));
+  /// assert_eq!(iter.next_back(), Some(([1, 2, 3, 2, 2], &7)));
+  /// ```
+  #[inline]
+  pub fn iter_nnz<'coo>(&'coo self) -> impl DoubleEndedIterator<Item = ([usize; D], &'coo DATA)>
+  where
+    DATA: 'coo,
+  {
+    self.data.as_ref().iter().map(|(indcs, elem)| (*indcs, elem))
+  }
+
+  /// Parallel version of [`SparseTensor::axis_nnz`](crate::SparseTensor::axis_nnz) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::{doc_tests::coo_array_5, SparseTensor};
+  /// let coo = coo_array_5();
+  /// assert_eq!(coo.par_axis_nnz(2), coo.axis_nnz(2));
+  /// ```
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[inline]
+  pub fn par_axis_nnz(&self, axis: usize) -> Option<Vec<usize>>
+  where
+    DATA: Sync,
+  {
+    crate::utils::par_axis_nnz(self.par_iter_nnz(), &self.dims, axis)
+  }
+
+  /// Parallel version of [`iter_nnz`](#method.iter_nnz) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::doc_tests::coo_array_5;
+  /// use rayon::prelude::*;
+  /// let coo = coo_array_5();
+  /// let elems: Vec<_> = coo.par_iter_nnz().collect();
+  /// assert_eq!(elems, coo.iter_nnz().collect::<Vec<_>>());
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_iter_nnz<'coo>(
+    &'coo self,
+  ) -> impl rayon::iter::IndexedParallelIterator<Item = ([usize; D], &'coo DATA)>
+  where
+    DATA: Sync + 'coo,
+  {
+    use rayon::prelude::*;
+    self.data.as_ref().par_iter().map(|(indcs, elem)| (*indcs, elem))
+  }
+
+  /// Copies all elements into a new structure described by `dims`, recomputing the coordinates
+  /// of each element according to its row-major position.
+  ///
+  /// Like in the other formats, zero dimensions are ignored, i.e., `[0, 2, 3]`, `[1, 2, 3]` and
+  /// `[0, 3, 2]` describe the same number of elements.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of new dimensions
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::{coo::CooVec, doc_tests::coo_array_5};
+  /// let coo = coo_array_5();
+  /// let matrix: CooVec<i32, 2> = coo.reshape([6, 36])?;
+  /// assert_eq!(matrix.value([0, 14]), Some(&1));
+  /// assert_eq!(matrix.value([5, 35]), Some(&7));
+  /// let back: CooVec<i32, 5> = matrix.reshape([2, 3, 4, 3, 3])?;
+  /// assert_eq!(back.data(), coo.data());
+  ///
+  /// let zero_dim = CooVec::new([0, 2, 3], vec![([0, 0, 1], 1), ([0, 1, 2], 2)])?;
+  /// let reshaped: CooVec<i32, 3> = zero_dim.reshape([1, 3, 2])?;
+  /// assert_eq!(reshaped.data(), &[([0, 0, 1], 1), ([0, 2, 1], 2)]);
+  /// let reshaped: CooVec<i32, 3> = zero_dim.reshape([0, 3, 2])?;
+  /// assert_eq!(reshaped.data(), &[([0, 0, 1], 1), ([0, 2, 1], 2)]);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn reshape<NDS, const E: usize>(&self, dims: [usize; E]) -> crate::Result<Coo<NDS, E>>
+  where
+    DATA: Clone,
+    NDS: Default + Push<([usize; E], DATA)>,
+  {
+    let curr_len = nonzero_dims_product(self.dims.as_slice());
+    if curr_len.is_none() || curr_len != nonzero_dims_product(&dims) {
+      return Err(CooError::InvalidReshapeDims.into());
+    }
+    let mut data = NDS::default();
+    for (indcs, elem) in self.data.as_ref() {
+      let idx = indcs_to_linear_idx(&self.dims, indcs).ok_or(CooError::InvalidIndcs)?;
+      let new_indcs = linear_idx_to_indcs(&dims, idx);
+      data.push((new_indcs, elem.clone())).map_err(|_err| crate::Error::InsufficientCapacity)?;
+    }
+    Ok(Coo { data, dims: dims.into() })
+  }
+
+  /// Accumulates all stored elements into the elements of `dense` that are located at the same
+  /// positions. `cb` receives the dense element followed by the sparse element.
+  ///
+  /// # Arguments
+  ///
+  /// * `dense`: Dense instance with the same dimensions of `self`
+  /// * `cb`: Accumulation callback
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooArray, dense::DenseArray};
+  /// let coo = CooArray::new([2, 2], [([0, 1], 5), ([1, 0], 7)]).unwrap();
+  /// let mut dense = DenseArray::new([2, 2], [1, 2, 3, 4]).unwrap();
+  /// coo.scatter(&mut dense, |dense_elem, coo_elem| *dense_elem += *coo_elem).unwrap();
+  /// assert_eq!(dense.data(), &[1, 7, 10, 4]);
+  /// ```
+  #[inline]
+  pub fn scatter<DDATA, DDS>(
+    &self,
+    dense: &mut Dense<DDS, D>,
+    mut cb: impl FnMut(&mut DDATA, &DATA),
+  ) -> crate::Result<()>
+  where
+    DDS: AsMut<[DDATA]> + SingleTypeStorage<Item = DDATA>,
+  {
+    if &self.dims.0 != dense.dims() {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    for (indcs, elem) in self.data.as_ref() {
+      cb(dense.value_mut(*indcs).ok_or(DenseError::IncompatibleDims)?, elem);
+    }
+    Ok(())
+  }
+
+  /// Checks all the invariants of a valid instance and returns the first violation, if any.
+  ///
+  /// Unlike [`new`](#method.new), violations related to a single element are returned as
+  /// [`crate::Error::CooAt`], which also contains the [`Location`] of the offending element.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::coo_array_5;
+  /// assert_eq!(coo_array_5().validate(), Ok(()));
+  /// ```
+  #[inline]
+  pub fn validate(&self) -> crate::Result<()> {
+    let dims = &self.dims.0;
+    let data = self.data();
+    let located =
+      |err, axis, pos, values| crate::Error::CooAt(err, Location { axis, line: None, pos, values });
+    for (pos, [(prev, _), (curr, _)]) in windows2(data).enumerate() {
+      if curr >= prev {
+        continue;
+      }
+      let diff =
+        prev.iter().zip(curr).enumerate().find(|(_, (prev_idx, curr_idx))| prev_idx != curr_idx);
+      let (axis, values) = diff.map_or((None, None), |(axis, (prev_idx, curr_idx))| {
+        (Some(axis), Some([*curr_idx, *prev_idx]))
+      });
+      return Err(located(CooError::InvalidIndcsOrder, axis, pos.saturating_add(1), values));
+    }
+    for (pos, (indcs, _)) in data.iter().enumerate() {
+      let mut iter = indcs.iter().zip(dims).enumerate();
+      if let Some((axis, (idx, dim))) = iter.find(|(_, (idx, dim))| **dim != 0 && idx >= dim) {
+        return Err(located(CooError::InvalidIndcs, Some(axis), pos, Some([*idx, *dim])));
+      }
+    }
+    if let Some(pos) = windows2(data).position(|[(prev, _), (curr, _)]| prev == curr) {
+      let curr_pos = pos.saturating_add(1);
+      return Err(located(CooError::DuplicatedIndices, None, curr_pos, Some([pos, curr_pos])));
+    }
+    Ok(())
+  }
+
   /// If any, retrieves an immutable data reference of a given set of indices.
   ///
   /// # Arguments
@@ -130,6 +365,30 @@ impl<DATA, DS, const D: usize> Coo<DS, D>
 where
   DS: AsMut<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = ([usize; D], DATA)>,
 {
+  /// Mutable version of [`iter_nnz`](#method.iter_nnz).
+  #[inline]
+  pub fn iter_nnz_mut<'coo>(
+    &'coo mut self,
+  ) -> impl DoubleEndedIterator<Item = ([usize; D], &'coo mut DATA)>
+  where
+    DATA: 'coo,
+  {
+    self.data.as_mut().iter_mut().map(|(indcs, elem)| (*indcs, elem))
+  }
+
+  /// Mutable version of [`par_iter_nnz`](#method.par_iter_nnz).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_iter_nnz_mut<'coo>(
+    &'coo mut self,
+  ) -> impl rayon::iter::IndexedParallelIterator<Item = ([usize; D], &'coo mut DATA)>
+  where
+    DATA: Send + 'coo,
+  {
+    use rayon::prelude::*;
+    self.data.as_mut().par_iter_mut().map(|(indcs, elem)| (*indcs, elem))
+  }
+
   /// Mutable version of [`value`](#method.value).
   #[inline]
   pub fn value_mut(&mut self, indcs: [usize; D]) -> Option<&mut DATA> {
@@ -145,7 +404,7 @@ where
     + Default
     + SingleTypeStorage<Item = ([usize; D], DATA)>
     + cl_aux::CapacityUpperBound
-    + cl_aux::Push<<DS as SingleTypeStorage>::Item>,
+    + Push<<DS as SingleTypeStorage>::Item>,
 {
   /// Creates a new random and valid instance delimited by the passed arguments.
   ///
@@ -165,6 +424,7 @@ where
   /// let dims = [1, 2, 3];
   /// let mut _random: ndstruct::Result<CooVec<u8, 3>>;
   /// _random = CooVec::new_controlled_random_rand(dims, 3, &mut rng, |r, _| r.gen());
+  /// assert!(_random.is_ok_and(|coo| !coo.data().is_empty()));
   /// ```
   #[inline]
   pub fn new_controlled_random_rand<R>(
@@ -181,7 +441,7 @@ where
       return Err(CooError::NnzGreaterThanMaximumNnz.into());
     }
     let mut data: DS = Default::default();
-    if nnz > data.as_ref().len() {
+    if nnz > data.capacity_upper_bound() {
       return Err(crate::Error::InsufficientCapacity);
     }
     for _ in 0..nnz {
@@ -238,3 +498,82 @@ where
     Self::new_controlled_random_rand(dims, nnz, rng, |r, _| r.r#gen())
   }
 }
+
+#[cfg(all(feature = "alloc", feature = "rand", feature = "rayon"))]
+impl<DATA, const D: usize> CooVec<DATA, D>
+where
+  DATA: Send,
+{
+  /// Parallel version of [`new_controlled_random_rand`](#method.new_controlled_random_rand) using
+  /// `rayon`.
+  ///
+  /// The outermost dimension is split into chunks and each chunk is generated by its own `R`,
+  /// which is seeded from `seed` and the chunk index, so the output only depends on the
+  /// arguments and is bit-identical regardless of the number of threads.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `nnz`: Number of Non-Zero elements
+  /// * `seed`: Master seed of all random streams
+  /// * `cb`: Callback to control data creation
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rand", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rand", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::coo::CooVec;
+  /// use rand::{rngs::SmallRng, Rng};
+  /// use rayon::ThreadPoolBuilder;
+  /// let generate = |threads| {
+  ///   ThreadPoolBuilder::new().num_threads(threads).build().unwrap().install(|| {
+  ///     CooVec::<u8, 3>::par_new_controlled_random_rand::<SmallRng>([8, 4, 4], 50, 7, |r, _| r.gen())
+  ///   })
+  /// };
+  /// assert_eq!(generate(1).unwrap(), generate(4).unwrap());
+  /// ```
+  #[inline]
+  pub fn par_new_controlled_random_rand<R>(
+    dims: [usize; D],
+    nnz: usize,
+    seed: u64,
+    cb: impl Fn(&mut R, &[usize; D]) -> DATA + Sync,
+  ) -> crate::Result<Self>
+  where
+    R: rand::Rng + rand::SeedableRng,
+  {
+    use rayon::prelude::*;
+    let outermost_dim = dims.first().copied().unwrap_or(0);
+    if outermost_dim == 0 {
+      let mut rng = R::seed_from_u64(crate::utils::stream_seed(seed, 0));
+      return Self::new_controlled_random_rand(dims, nnz, &mut rng, cb);
+    }
+    if nnz > crate::utils::max_nnz(&dims) {
+      return Err(CooError::NnzGreaterThanMaximumNnz.into());
+    }
+    let chunks = crate::utils::par_random_chunks(outermost_dim, nnz)
+      .ok_or(crate::Error::UnknownError)?
+      .into_par_iter()
+      .enumerate()
+      .map(|(chunk_idx, (range, chunk_nnz))| {
+        let mut chunk_dims = dims;
+        *chunk_dims.first_mut().ok_or(crate::Error::UnknownError)? = range.len();
+        let mut rng = R::seed_from_u64(crate::utils::stream_seed(seed, chunk_idx));
+        let mut chunk =
+          Self::new_controlled_random_rand(chunk_dims, chunk_nnz, &mut rng, |r, indcs| {
+            let mut shifted = *indcs;
+            if let Some(elem) = shifted.first_mut() {
+              *elem = elem.saturating_add(range.start);
+            }
+            cb(r, &shifted)
+          })?;
+        for (indcs, _) in &mut chunk.data {
+          if let Some(elem) = indcs.first_mut() {
+            *elem = elem.saturating_add(range.start);
+          }
+        }
+        Ok(chunk.data)
+      })
+      .collect::<crate::Result<Vec<_>>>()?;
+    Ok(Self { data: chunks.into_iter().flatten().collect(), dims: dims.into() })
+  }
+}
@@ -22,6 +22,16 @@ pub enum CooError {
   /// ```
   InvalidIndcs,
 
+  /// The new dimensions don't describe the same number of elements of the current dimensions
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::{coo::{CooError, CooVec}, doc_tests::coo_array_5};
+  /// let coo: ndstruct::Result<CooVec<i32, 2>> = coo_array_5().reshape([6, 6]);
+  /// assert_eq!(coo, Err(ndstruct::Error::Coo(CooError::InvalidReshapeDims)));
+  /// ```
+  InvalidReshapeDims,
+
   /// There are duplicated indices
   ///
   /// ```rust
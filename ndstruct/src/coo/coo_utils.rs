@@ -1,5 +1,3 @@
-use crate::utils::windows2;
-
 macro_rules! create_value {
   ($get:ident $fn_name:ident $([$mut:tt])?) => {
     #[inline]
@@ -18,11 +16,3 @@ macro_rules! create_value {
 
 create_value!(get value);
 create_value!(get_mut value_mut [mut]);
-
-#[inline]
-pub(crate) fn does_not_have_duplicates_sorted<T>(
-  slice: &[T],
-  mut cb: impl FnMut(&T, &T) -> bool,
-) -> bool {
-  windows2(slice).all(|[a, b]| cb(a, b))
-}
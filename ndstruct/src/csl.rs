@@ -7,23 +7,48 @@
 //! [`CSC`]: en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_column_(CSC_or_CCS)
 //! [`CSR`]: en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
 
+#[cfg(feature = "alloc")]
+mod csl_amd;
+#[cfg(feature = "rayon")]
+mod csl_chunk_iter;
+mod csl_dense;
 mod csl_error;
+mod csl_kernels;
 mod csl_line_constructor;
 mod csl_line_iter;
+mod csl_nnz_iter;
+#[cfg(feature = "alloc")]
+mod csl_ordering;
 #[cfg(feature = "rayon")]
 mod csl_rayon;
 #[cfg(feature = "rand")]
 mod csl_rnd;
+mod csl_stats;
+mod csl_sym;
+#[cfg(feature = "alloc")]
+mod csl_unsorted;
 pub(crate) mod csl_utils;
 
-use crate::utils::{are_in_ascending_order, are_in_upper_bound, has_duplicates, max_nnz, windows2};
+use crate::utils::{duplicated_pos, max_nnz, windows2};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use cl_aux::{ArrayWrapper, Clear, Push, SingleTypeStorage, Truncate, WithCapacity};
 use core::ops::Range;
+#[cfg(feature = "rayon")]
+use csl_chunk_iter::ChunkLen;
+#[cfg(feature = "rayon")]
+pub use csl_chunk_iter::{CslChunkIterMut, CslChunkIterRef};
 pub use csl_error::*;
+#[cfg(feature = "rayon")]
+pub use csl_kernels::CslMergePathPart;
 pub use csl_line_constructor::*;
 pub use csl_line_iter::*;
+pub use csl_nnz_iter::*;
+#[cfg(feature = "rand")]
+pub use csl_rnd::CslRndBuilder;
+#[cfg(feature = "rand")]
+use csl_rnd::{LineNnz, Pattern};
+pub use csl_sym::*;
 
 /// CSL backed by a static array.
 pub type CslArray<DATA, const D: usize, const N: usize, const O: usize> =
@@ -58,6 +83,13 @@ pub type CslVec<DATA, const D: usize> = Csl<Vec<DATA>, Vec<usize>, Vec<usize>, D
 /// * `IS`: Indices Store
 /// * `OS`: Offsets Store
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+  feature = "serde",
+  expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "Deserialized instances aren't validated and should be checked with `validate`"
+  )
+)]
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Csl<DS, IS, OS, const D: usize> {
   pub(crate) data: DS,
@@ -148,78 +180,124 @@ where
   /// ```
   #[inline]
   pub fn new(dims: [usize; D], data: DS, indcs: IS, offs: OS) -> crate::Result<Self> {
-    let data_ref = data.as_ref();
-    let indcs_ref = indcs.as_ref();
-    let offs_ref = offs.as_ref();
-
-    let innermost_dim_is_zero = {
-      let mut iter = dims.iter().copied();
-      for dim in &mut iter {
-        if dim != 0 {
-          break;
-        }
-      }
-      iter.any(|v| v == 0)
-    };
-    if innermost_dim_is_zero {
-      return Err(CslError::InnermostDimsZero.into());
-    }
-
-    if data_ref.len() != indcs_ref.len() {
-      return Err(CslError::DiffDataIndcsLength.into());
-    }
-
-    if !are_in_ascending_order(offs_ref, |a, b| [a, b]) {
-      return Err(CslError::InvalidOffsetsOrder.into());
-    }
-
-    let data_indcs_length_greater_than_dims_length = {
-      let max_nnz = max_nnz(&dims);
-      data_ref.len() > max_nnz || indcs_ref.len() > max_nnz
-    };
-    if data_indcs_length_greater_than_dims_length {
-      return Err(CslError::DataIndcsLengthGreaterThanDimsLength.into());
-    }
-
-    if let Some(last) = dims.last() {
-      let are_in_upper_bound = are_in_upper_bound(indcs_ref, last);
-      if !are_in_upper_bound {
-        return Err(CslError::IndcsGreaterThanEqualDimLength.into());
-      }
-      if offs_ref.len() != csl_utils::correct_offs_len(&dims)? {
-        return Err(CslError::InvalidOffsetsLength.into());
-      }
-    }
+    let rslt = Self { data, dims: dims.into(), indcs, offs };
+    rslt.validate().map_err(crate::Error::without_location)?;
+    Ok(rslt)
+  }
 
-    let Some(first_off) = offs_ref.first().copied() else {
-      return Ok(Self { data, dims: dims.into(), indcs, offs });
-    };
+  /// Same as [`new`](#method.new) but violations related to a single element are returned as
+  /// [`crate::Error::CslAt`], which also contains the [`Location`](crate::Location) of the
+  /// offending element. Useful to debug instances imported from external sources.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `data`: Data collection
+  /// * `indcs`: Indices of each data item
+  /// * `offs`: Offset of each innermost line
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::{CslArray, CslError}, Error};
+  /// let err = CslArray::new_located([2, 3], [1, 2, 3], [1, 2, 1], [0, 3, 3]).unwrap_err();
+  /// let location = err.location().unwrap();
+  /// assert_eq!((location.line, location.pos, location.values), (Some(0), 2, Some([0, 2])));
+  /// assert_eq!(err.without_location(), Error::Csl(CslError::DuplicatedIndices));
+  /// ```
+  #[inline]
+  pub fn new_located(dims: [usize; D], data: DS, indcs: IS, offs: OS) -> crate::Result<Self> {
+    let rslt = Self { data, dims: dims.into(), indcs, offs };
+    rslt.validate()?;
+    Ok(rslt)
+  }
 
-    if let Some(last_ref) = offs_ref.last() {
-      if let Some(last) = last_ref.checked_sub(first_off) {
-        if last != data_ref.len() || last != indcs_ref.len() {
-          return Err(CslError::LastOffsetDifferentNnz.into());
-        }
-      }
-    }
+  /// Creates a CSL instance without validating the arguments in release builds.
+  ///
+  /// Useful for hot paths that build instances from parts that are already known to be valid.
+  /// Under `debug_assertions`, the arguments are fully validated and invalid instances panic.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `data`: Data collection
+  /// * `indcs`: Indices of each data item
+  /// * `offs`: Offset of each innermost line
+  ///
+  /// # Safety
+  ///
+  /// The arguments must uphold all the invariants checked by [`validate`](#method.validate),
+  /// otherwise all other methods can return wrong results or panic.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::CslArray;
+  /// // SAFETY: Offsets, indices and data describe a valid sparse array
+  /// let csl = unsafe { CslArray::new_unchecked([10], [8.0, 9.0], [0, 5], [0, 2]) };
+  /// assert_eq!(csl.value([5]), Some(&9.0));
+  /// ```
+  #[expect(unsafe_code, reason = "The validation of the arguments is delegated to the caller")]
+  #[inline]
+  pub unsafe fn new_unchecked(dims: [usize; D], data: DS, indcs: IS, offs: OS) -> Self {
+    let rslt = Self { data, dims: dims.into(), indcs, offs };
+    debug_assert!(rslt.validate().is_ok(), "Invalid CSL instance: {:?}", rslt.validate());
+    rslt
+  }
 
-    let has_duplicated_indices = windows2(offs_ref).any(|[a, b]| {
-      let fun = || {
-        let first = a.checked_sub(first_off)?;
-        let last = b.checked_sub(first_off)?;
-        indcs_ref.get(first..last)
-      };
-      if let Some(indcs_slice) = fun() {
-        has_duplicates(indcs_slice)
-      } else {
-        false
-      }
-    });
-    if has_duplicated_indices {
-      return Err(CslError::DuplicatedIndices.into());
-    }
+  /// Iterator that returns immutable line references of all index combinations of the
+  /// dimensions up to and including `axis`.
+  ///
+  /// The outermost dimension is `0` and the innermost dimension isn't allowed, i.e., the last
+  /// valid axis returns the innermost lines.
+  ///
+  /// # Arguments
+  ///
+  /// * `axis`: Index of the last fixed dimension
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::{csl::CslRef, doc_tests::csl_array_4};
+  /// let csl = csl_array_4();
+  /// let mut iter = csl.axis_line_iter(1)?;
+  /// assert_eq!(iter.len(), 6);
+  /// assert_eq!(iter.nth(1), CslRef::new([1, 1, 4, 5], &[6][..], &[2][..], &[5, 6, 6, 6, 6][..]).ok());
+  /// assert_eq!(csl.axis_line_iter(2)?.filter(|line| line.nnz() > 0).count(), 7);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn axis_line_iter(&self, axis: usize) -> crate::Result<CslLineIterRef<'_, DATA, D>> {
+    CslLineIterRef::with_axis(
+      axis,
+      self.dims.0,
+      self.data.as_ref(),
+      self.indcs.as_ref(),
+      self.offs.as_ref(),
+    )
+  }
 
-    Ok(Self { data, dims: dims.into(), indcs, offs })
+  /// Parallel version of [`axis_line_iter`](#method.axis_line_iter) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// use rayon::prelude::*;
+  /// let csl = csl_array_4();
+  /// let nnz: Vec<_> = csl.axis_line_rayon_iter(1)?.map(|line| line.nnz()).collect();
+  /// assert_eq!(nnz, [5, 1, 2, 1, 0, 0]);
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn axis_line_rayon_iter(
+    &self,
+    axis: usize,
+  ) -> crate::Result<crate::ParallelIteratorWrapper<CslLineIterRef<'_, DATA, D>>> {
+    Ok(crate::ParallelIteratorWrapper(self.axis_line_iter(axis)?))
   }
 
   /// The data that is being stored.
@@ -248,6 +326,25 @@ where
     self.indcs.as_ref()
   }
 
+  /// Iterator that returns all non-zero elements in storage order alongside the indices of all
+  /// dimensions.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// let csl = csl_array_4();
+  /// let mut iter = csl.iter_nnz();
+  /// assert_eq!(iter.next(), Some(([0, 0, 0, 0], &1)));
+  /// assert_eq!(iter.next(), Some(([0, 0, 0, 3], &2)));
+  /// assert_eq!(iter.next_back(), Some(([1, 0, 2, 2], &9)));
+  /// assert!(iter.all(|(indcs, elem)| csl.value(indcs) == Some(elem)));
+  /// ```
+  #[inline]
+  pub fn iter_nnz(&self) -> CslNnzIterRef<'_, DATA, D> {
+    CslNnzIterRef::new(self.dims.0, self.data.as_ref(), self.indcs.as_ref(), self.offs.as_ref())
+  }
+
   /// Any immutable line reference determined by `indcs`. The innermost dimension is ignored.
   ///
   /// # Examples
@@ -343,6 +440,131 @@ where
     Ok(crate::ParallelIteratorWrapper(self.outermost_line_iter()?))
   }
 
+  /// Parallel iterator that returns immutable sub-tensors of up to `lines` outermost lines
+  /// using `rayon`.
+  ///
+  /// # Arguments
+  ///
+  /// * `lines`: Maximum number of outermost lines of each sub-tensor
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::{csl::CslVec, doc_tests::csl_array_4};
+  /// use rayon::prelude::*;
+  /// let csl = csl_array_4().reshape([24, 5])?;
+  /// let chunks: Vec<_> = csl.par_chunks(10)?.collect();
+  /// assert_eq!(chunks.iter().map(|chunk| chunk.dims()[0]).collect::<Vec<_>>(), [10, 10, 4]);
+  /// assert_eq!(chunks[0].value([4, 2]), Some(&6));
+  /// assert_eq!(chunks[1].value([4, 2]), Some(&9));
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_chunks(
+    &self,
+    lines: usize,
+  ) -> crate::Result<crate::ParallelIteratorWrapper<CslChunkIterRef<'_, DATA, D>>> {
+    Ok(crate::ParallelIteratorWrapper(CslChunkIterRef::new(
+      ChunkLen::Lines(lines),
+      self.dims.0,
+      self.data.as_ref(),
+      self.indcs.as_ref(),
+      self.offs.as_ref(),
+    )?))
+  }
+
+  /// Parallel version of [`iter_nnz`](#method.iter_nnz) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// use rayon::prelude::*;
+  /// let csl = csl_array_4();
+  /// let elems: Vec<_> = csl.par_iter_nnz().collect();
+  /// assert_eq!(elems, csl.iter_nnz().collect::<Vec<_>>());
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_iter_nnz(&self) -> crate::ParallelIteratorWrapper<CslNnzIterRef<'_, DATA, D>> {
+    crate::ParallelIteratorWrapper(self.iter_nnz())
+  }
+
+  /// Parallel iterator that splits the outermost lines into up to `chunks` contiguous immutable
+  /// sub-tensors with approximately the same number of non-zero elements using `rayon`.
+  ///
+  /// Unlike [`outermost_line_rayon_iter`](#method.outermost_line_rayon_iter), a few heavy lines
+  /// don't serialize the work of the remaining lines. A line is never split, which means that
+  /// sub-tensors located after a line with more elements than the desired average can be empty.
+  ///
+  /// # Arguments
+  ///
+  /// * `chunks`: Maximum number of sub-tensors
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// use rayon::prelude::*;
+  /// let csl = csl_array_4().reshape([24, 5])?;
+  /// let chunks: Vec<_> = csl.par_nnz_balanced_chunks(3)?.collect();
+  /// assert_eq!(chunks.iter().map(|chunk| chunk.dims()[0]).collect::<Vec<_>>(), [2, 3, 19]);
+  /// assert_eq!(chunks.iter().map(|chunk| chunk.nnz()).collect::<Vec<_>>(), [3, 3, 3]);
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_nnz_balanced_chunks(
+    &self,
+    chunks: usize,
+  ) -> crate::Result<crate::ParallelIteratorWrapper<CslChunkIterRef<'_, DATA, D>>> {
+    Ok(crate::ParallelIteratorWrapper(CslChunkIterRef::new(
+      ChunkLen::Nnz(chunks),
+      self.dims.0,
+      self.data.as_ref(),
+      self.indcs.as_ref(),
+      self.offs.as_ref(),
+    )?))
+  }
+
+  /// Reinterprets the current structure with a new set of dimensions without copying or
+  /// moving any data.
+  ///
+  /// Only the outermost dimensions can be merged or split, i.e., the innermost dimension and
+  /// the number of lines must remain the same so that the current offsets stay valid.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of new dimensions
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::{csl::CslRef, doc_tests::csl_array_4};
+  /// let matrix = csl_array_4().reshape([24, 5])?;
+  /// assert_eq!(matrix.dims(), &[24, 5]);
+  /// assert_eq!(matrix.value([14, 2]), Some(&9));
+  /// assert_eq!(matrix.line([3, 0]), CslRef::new([5], &[4, 5][..], &[3, 4][..], &[3, 5][..]).ok());
+  /// let cuboid = matrix.reshape([4, 6, 5])?;
+  /// assert_eq!(cuboid.value([2, 2, 2]), Some(&9));
+  /// assert!(cuboid.reshape([24, 1, 5]).is_ok());
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn reshape<const E: usize>(self, dims: [usize; E]) -> crate::Result<Csl<DS, IS, OS, E>> {
+    if dims.last() != self.dims.last() {
+      return Err(CslError::InvalidReshapeInnermostDim.into());
+    }
+    if csl_utils::correct_offs_len(&dims)? != self.offs.as_ref().len() {
+      return Err(CslError::InvalidReshapeDims.into());
+    }
+    Ok(Csl { data: self.data, dims: dims.into(), indcs: self.indcs, offs: self.offs })
+  }
+
   /// Retrieves an immutable reference of any sub dimension.
   ///
   /// # Arguments
@@ -371,6 +593,98 @@ where
     csl_utils::sub_dim(self, range)
   }
 
+  /// Checks all the invariants of a valid instance and returns the first violation, if any.
+  ///
+  /// Unlike [`new`](#method.new), violations related to a single element or offset are returned
+  /// as [`crate::Error::CslAt`], which also contains the [`crate::Location`] of the offending
+  /// element.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// assert_eq!(csl_array_4().validate(), Ok(()));
+  /// ```
+  #[inline]
+  pub fn validate(&self) -> crate::Result<()> {
+    let dims = &self.dims.0;
+    let data_ref = self.data.as_ref();
+    let indcs_ref = self.indcs.as_ref();
+    let offs_ref = self.offs.as_ref();
+
+    let innermost_dim_is_zero = {
+      let mut iter = dims.iter().copied();
+      for dim in &mut iter {
+        if dim != 0 {
+          break;
+        }
+      }
+      iter.any(|v| v == 0)
+    };
+    if innermost_dim_is_zero {
+      return Err(CslError::InnermostDimsZero.into());
+    }
+
+    if data_ref.len() != indcs_ref.len() {
+      return Err(CslError::DiffDataIndcsLength.into());
+    }
+
+    if let Some((pos, [a, b])) = windows2(offs_ref).enumerate().find(|(_, [a, b])| a > b) {
+      let values = [*b, *a];
+      return Err(csl_utils::located(
+        CslError::InvalidOffsetsOrder,
+        None,
+        pos.saturating_add(1),
+        values,
+      ));
+    }
+
+    let data_indcs_length_greater_than_dims_length = {
+      let max_nnz = max_nnz(dims);
+      data_ref.len() > max_nnz || indcs_ref.len() > max_nnz
+    };
+    if data_indcs_length_greater_than_dims_length {
+      return Err(CslError::DataIndcsLengthGreaterThanDimsLength.into());
+    }
+
+    if let Some(last) = dims.last() {
+      if let Some((pos, idx)) = indcs_ref.iter().enumerate().find(|(_, idx)| *idx >= last) {
+        let err = CslError::IndcsGreaterThanEqualDimLength;
+        return Err(csl_utils::located(err, None, pos, [*idx, *last]));
+      }
+      if offs_ref.len() != csl_utils::correct_offs_len(dims)? {
+        return Err(CslError::InvalidOffsetsLength.into());
+      }
+    }
+
+    let Some(first_off) = offs_ref.first().copied() else {
+      return Ok(());
+    };
+
+    if let Some(last_ref) = offs_ref.last() {
+      if let Some(last) = last_ref.checked_sub(first_off) {
+        if last != data_ref.len() || last != indcs_ref.len() {
+          return Err(CslError::LastOffsetDifferentNnz.into());
+        }
+      }
+    }
+
+    for (line, [a, b]) in windows2(offs_ref).enumerate() {
+      let first = a.saturating_sub(first_off);
+      let last = b.saturating_sub(first_off);
+      let Some(line_indcs) = indcs_ref.get(first..last) else {
+        continue;
+      };
+      if let Some([prev_pos, line_pos]) = duplicated_pos(line_indcs) {
+        let pos = first.saturating_add(line_pos);
+        let values = [first.saturating_add(prev_pos), pos];
+        return Err(csl_utils::located(CslError::DuplicatedIndices, Some(line), pos, values));
+      }
+    }
+
+    Ok(())
+  }
+
   /// Retrieves an immutable reference of a single data value.
   ///
   /// # Arguments
@@ -399,6 +713,28 @@ where
   IS: AsRef<[usize]>,
   OS: AsRef<[usize]>,
 {
+  /// Mutable version of [`axis_line_iter`](#method.axis_line_iter).
+  #[inline]
+  pub fn axis_line_iter_mut(&mut self, axis: usize) -> crate::Result<CslLineIterMut<'_, DATA, D>> {
+    CslLineIterMut::with_axis(
+      axis,
+      self.dims.0,
+      self.data.as_mut(),
+      self.indcs.as_ref(),
+      self.offs.as_ref(),
+    )
+  }
+
+  /// Mutable version of [`axis_line_rayon_iter`](#method.axis_line_rayon_iter).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn axis_line_rayon_iter_mut(
+    &mut self,
+    axis: usize,
+  ) -> crate::Result<crate::ParallelIteratorWrapper<CslLineIterMut<'_, DATA, D>>> {
+    Ok(crate::ParallelIteratorWrapper(self.axis_line_iter_mut(axis)?))
+  }
+
   /// Clears all values and dimensions.
   ///
   /// # Example
@@ -439,6 +775,12 @@ where
     self.data.as_mut()
   }
 
+  /// Mutable version of [`iter_nnz`](#method.iter_nnz).
+  #[inline]
+  pub fn iter_nnz_mut(&mut self) -> CslNnzIterMut<'_, DATA, D> {
+    CslNnzIterMut::new(self.dims.0, self.data.as_mut(), self.indcs.as_ref(), self.offs.as_ref())
+  }
+
   /// Mutable version of [`line`](#method.line).
   #[inline]
   pub fn line_mut(&mut self, indcs: [usize; D]) -> Option<CslMut<'_, DATA, 1>> {
@@ -460,6 +802,45 @@ where
     Ok(crate::ParallelIteratorWrapper(self.outermost_line_iter_mut()?))
   }
 
+  /// Mutable version of [`par_chunks`](#method.par_chunks).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_chunks_mut(
+    &mut self,
+    lines: usize,
+  ) -> crate::Result<crate::ParallelIteratorWrapper<CslChunkIterMut<'_, DATA, D>>> {
+    Ok(crate::ParallelIteratorWrapper(CslChunkIterMut::new(
+      ChunkLen::Lines(lines),
+      self.dims.0,
+      self.data.as_mut(),
+      self.indcs.as_ref(),
+      self.offs.as_ref(),
+    )?))
+  }
+
+  /// Mutable version of [`par_iter_nnz`](#method.par_iter_nnz).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_iter_nnz_mut(&mut self) -> crate::ParallelIteratorWrapper<CslNnzIterMut<'_, DATA, D>> {
+    crate::ParallelIteratorWrapper(self.iter_nnz_mut())
+  }
+
+  /// Mutable version of [`par_nnz_balanced_chunks`](#method.par_nnz_balanced_chunks).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_nnz_balanced_chunks_mut(
+    &mut self,
+    chunks: usize,
+  ) -> crate::Result<crate::ParallelIteratorWrapper<CslChunkIterMut<'_, DATA, D>>> {
+    Ok(crate::ParallelIteratorWrapper(CslChunkIterMut::new(
+      ChunkLen::Nnz(chunks),
+      self.dims.0,
+      self.data.as_mut(),
+      self.indcs.as_ref(),
+      self.offs.as_ref(),
+    )?))
+  }
+
   /// Mutable version of [`sub_dim`](#method.sub_dim).
   #[inline]
   pub fn sub_dim_mut<const TD: usize>(
@@ -543,6 +924,110 @@ where
   }
 }
 
+#[cfg(feature = "alloc")]
+impl<DATA, const D: usize> CslVec<DATA, D> {
+  /// Removes the lines of the outermost dimension that are located in `range` and returns them
+  /// as a new instance.
+  ///
+  /// Offsets of the remaining lines are shifted accordingly and the outermost dimension of both
+  /// instances reflects their new number of lines. An instance without lines ends up with all
+  /// dimensions set to zero.
+  ///
+  /// # Arguments
+  ///
+  /// * `range`: Starting and ending of the outermost lines that will be removed
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::{csl::CslVec, doc_tests::csl_vec_4};
+  /// let mut csl = csl_vec_4();
+  /// let first = csl.drain_lines(0..1)?;
+  /// assert_eq!(first.dims(), &[1, 3, 4, 5]);
+  /// assert_eq!(first.data(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+  /// assert_eq!(csl, CslVec::new([1, 3, 4, 5], vec![9], vec![2], vec![0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1])?);
+  /// let last = csl.drain_lines(0..1)?;
+  /// assert_eq!(csl, CslVec::new([0, 0, 0, 0], vec![], vec![], vec![0])?);
+  /// assert_eq!(last.value([0, 0, 2, 2]), Some(&9));
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn drain_lines(&mut self, range: Range<usize>) -> crate::Result<Self> {
+    if D < 2 {
+      return Err(CslError::InvalidIterDim.into());
+    }
+    let outermost_dim = self.dims.0.first().copied().unwrap_or(0);
+    if range.start > range.end || range.end > outermost_dim {
+      return Err(CslError::InvalidLineRange.into());
+    }
+    let lines = range.end.wrapping_sub(range.start);
+    let [offs_range, values] = csl_utils::outermost_offs(&self.dims.0, &self.offs, range);
+    let nnz = values.end.checked_sub(values.start).ok_or(crate::Error::UnknownError)?;
+    let first_off = self.offs.first().copied().unwrap_or(0);
+    let data_start = values.start.checked_sub(first_off).ok_or(crate::Error::UnknownError)?;
+    let data_range = data_start..data_start.checked_add(nnz).ok_or(crate::Error::UnknownError)?;
+    if self.offs.get(offs_range.clone()).is_none() || self.data.get(data_range.clone()).is_none() {
+      return Err(crate::Error::UnknownError);
+    }
+
+    let mut drained = Self {
+      data: self.data.drain(data_range.clone()).collect(),
+      dims: self.dims,
+      indcs: self.indcs.drain(data_range).collect(),
+      offs: self.offs.get(offs_range.clone()).unwrap_or_default().to_vec(),
+    };
+    drained.offs.iter_mut().for_each(|off| *off = off.saturating_sub(values.start));
+    drop(self.offs.drain(offs_range.start.saturating_add(1)..offs_range.end));
+    self.offs.iter_mut().skip(offs_range.start.saturating_add(1)).for_each(|off| {
+      *off = off.saturating_sub(nnz);
+    });
+    for (csl, outermost_lines) in
+      [(&mut *self, outermost_dim.saturating_sub(lines)), (&mut drained, lines)]
+    {
+      match csl.dims.0.first_mut() {
+        Some(first) if outermost_lines > 0 => *first = outermost_lines,
+        _ => {
+          csl.dims = ArrayWrapper::default();
+          csl.offs.truncate(1);
+        }
+      }
+    }
+    Ok(drained)
+  }
+
+  /// Consumes this instance and returns an iterator of owned lines of the outermost dimension.
+  ///
+  /// Each line is a standalone valid instance whose outermost dimension is equal to one and
+  /// whose offsets start at zero. Instances with less than two dimensions are rejected because
+  /// their single line is the instance itself.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::{csl::{CslError, CslVec}, doc_tests::csl_vec_4};
+  /// let vector = CslVec::new([3], vec![1, 2], vec![0, 2], vec![0, 2])?;
+  /// assert!(matches!(
+  ///   vector.into_outermost_line_iter(),
+  ///   Err(ndstruct::Error::Csl(CslError::InvalidIterDim))
+  /// ));
+  /// let mut iter = csl_vec_4().into_outermost_line_iter()?;
+  /// assert_eq!(iter.len(), 2);
+  /// assert_eq!(
+  ///   iter.next_back(),
+  ///   CslVec::new([1, 3, 4, 5], vec![9], vec![2], vec![0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]).ok()
+  /// );
+  /// assert_eq!(iter.next().map(|line| line.nnz()), Some(8));
+  /// assert_eq!(iter.next(), None);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn into_outermost_line_iter(self) -> crate::Result<CslLineIntoIter<DATA, D>> {
+    CslLineIntoIter::new(self.dims.0, self.data, self.indcs, self.offs)
+  }
+}
+
 #[cfg(feature = "rand")]
 impl<DATA, DS, IS, OS, const D: usize> Csl<DS, IS, OS, D>
 where
@@ -559,6 +1044,8 @@ where
   /// * `rng`: `rand::Rng` trait
   /// * `cb`: Callback to control data creation
   ///
+  /// See [`CslRndBuilder`] for other distributions and patterns.
+  ///
   /// # Example
   #[cfg_attr(feature = "alloc", doc = "```rust")]
   #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
@@ -568,6 +1055,10 @@ where
   /// let dims = [1, 2, 3];
   /// let mut _random: ndstruct::Result<CslVec<u8, 3>>;
   /// _random = CslVec::new_controlled_random_rand(dims, 9, &mut rng, |r, _| r.gen());
+  ///
+  /// // The callback receives the indices of each element
+  /// let csl = CslVec::new_controlled_random_rand([2, 2, 3], 6, &mut rng, |_, indcs| indcs).unwrap();
+  /// assert!(csl.iter_nnz().all(|(indcs, elem)| indcs == *elem));
   /// ```
   #[inline]
   pub fn new_controlled_random_rand<R>(
@@ -580,7 +1071,9 @@ where
     R: rand::Rng,
   {
     let mut csl = Csl { dims: dims.into(), ..Default::default() };
-    csl_rnd::CslRnd::new(&mut csl, nnz, rng)?.fill(cb).ok_or(crate::Error::UnknownError)?;
+    csl_rnd::CslRnd::new(&mut csl, LineNnz::Uniform(nnz), Pattern::Any, false, rng)?
+      .fill(cb)
+      .ok_or(crate::Error::UnknownError)?;
     Self::new(csl.dims.0, csl.data, csl.indcs, csl.offs)
   }
 
@@ -617,3 +1110,90 @@ where
     Self::new_controlled_random_rand(dims, nnz, rng, |r, _| r.r#gen())
   }
 }
+
+#[cfg(all(feature = "alloc", feature = "rand", feature = "rayon"))]
+impl<DATA, const D: usize> CslVec<DATA, D>
+where
+  DATA: Send,
+{
+  /// Parallel version of [`new_controlled_random_rand`](#method.new_controlled_random_rand) using
+  /// `rayon`.
+  ///
+  /// The outermost dimension is split into chunks and each chunk is generated by its own `R`,
+  /// which is seeded from `seed` and the chunk index. `nnz` is evenly distributed among all
+  /// outermost indices, which means that the output only depends on the arguments and is
+  /// bit-identical regardless of the number of threads.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `nnz`: Number of Non-Zero elements
+  /// * `seed`: Master seed of all random streams
+  /// * `cb`: Callback to control data creation
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rand", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rand", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::csl::CslVec;
+  /// use rand::{rngs::SmallRng, Rng};
+  /// use rayon::ThreadPoolBuilder;
+  /// let generate = |threads| {
+  ///   ThreadPoolBuilder::new().num_threads(threads).build().unwrap().install(|| {
+  ///     CslVec::<u8, 3>::par_new_controlled_random_rand::<SmallRng>([8, 4, 4], 50, 7, |r, _| r.gen())
+  ///   })
+  /// };
+  /// let csl = generate(1).unwrap();
+  /// assert_eq!(csl.nnz(), 50);
+  /// assert_eq!(csl, generate(4).unwrap());
+  /// ```
+  #[inline]
+  pub fn par_new_controlled_random_rand<R>(
+    dims: [usize; D],
+    nnz: usize,
+    seed: u64,
+    cb: impl Fn(&mut R, [usize; D]) -> DATA + Sync,
+  ) -> crate::Result<Self>
+  where
+    R: rand::Rng + rand::SeedableRng,
+  {
+    use rayon::prelude::*;
+    let outermost_dim = if D < 2 { 0 } else { dims.first().copied().unwrap_or(0) };
+    if outermost_dim == 0 {
+      let mut rng = R::seed_from_u64(crate::utils::stream_seed(seed, 0));
+      return Self::new_controlled_random_rand(dims, nnz, &mut rng, cb);
+    }
+    if nnz > max_nnz(&dims) {
+      return Err(CslError::NnzGreaterThanMaximumNnz.into());
+    }
+    let chunks = crate::utils::par_random_chunks(outermost_dim, nnz)
+      .ok_or(crate::Error::UnknownError)?
+      .into_par_iter()
+      .enumerate()
+      .map(|(chunk_idx, (range, chunk_nnz))| {
+        let mut chunk_dims = dims;
+        *chunk_dims.first_mut().ok_or(crate::Error::UnknownError)? = range.len();
+        let mut rng = R::seed_from_u64(crate::utils::stream_seed(seed, chunk_idx));
+        Self::new_controlled_random_rand(chunk_dims, chunk_nnz, &mut rng, |local_rng, mut indcs| {
+          if let Some(elem) = indcs.first_mut() {
+            *elem = elem.saturating_add(range.start);
+          }
+          cb(local_rng, indcs)
+        })
+      })
+      .collect::<crate::Result<Vec<_>>>()?;
+    let mut rslt = Self {
+      data: Vec::with_capacity(nnz),
+      dims: dims.into(),
+      indcs: Vec::with_capacity(nnz),
+      offs: Vec::with_capacity(csl_utils::correct_offs_len(&dims)?),
+    };
+    rslt.offs.push(0);
+    for chunk in chunks {
+      let last_off = rslt.indcs.len();
+      rslt.data.extend(chunk.data);
+      rslt.indcs.extend(chunk.indcs);
+      rslt.offs.extend(chunk.offs.into_iter().skip(1).map(|off| off.saturating_add(last_off)));
+    }
+    Ok(rslt)
+  }
+}
@@ -0,0 +1,241 @@
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::{cmp::Reverse, mem::take};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Node {
+  // Element that was absorbed by another element
+  Absorbed,
+  // Eliminated variable that represents a clique of the elimination graph
+  Element,
+  // Variable that was merged into an indistinguishable variable
+  Merged,
+  // Principal variable of a supervariable that wasn't eliminated yet
+  Variable,
+}
+
+// Approximate Minimum Degree ordering of the graph described by the symmetric `adjacency`. The
+// returned vector maps new indices to old indices.
+//
+// Eliminated variables become elements of a quotient graph, which means that the fill of the
+// elimination graph is never explicitly formed.
+pub(crate) fn approximate_minimum_degree(adjacency: Vec<Vec<usize>>) -> Option<Vec<usize>> {
+  let mut amd = Amd::new(adjacency);
+  let mut perm = Vec::with_capacity(amd.nodes.len());
+  while let Some(Reverse((degree, pivot))) = amd.heap.pop() {
+    if at(&amd.nodes, pivot)? != Node::Variable || at(&amd.degrees, pivot)? != degree {
+      continue;
+    }
+    perm.append(amd.members.get_mut(pivot)?);
+    amd.eliminate(pivot)?;
+  }
+  Some(perm)
+}
+
+struct Amd {
+  // Approximate external degree of each variable
+  degrees: Vec<usize>,
+  // Variables of each element
+  elem_vars: Vec<Vec<usize>>,
+  // Adjacent elements of each variable
+  elems: Vec<Vec<usize>>,
+  // Generation and weight of the variables of an element that don't belong to the current pivot
+  // element
+  ext: Vec<(usize, usize)>,
+  generation: usize,
+  heap: BinaryHeap<Reverse<(usize, usize)>>,
+  marks: Vec<usize>,
+  // Original variables represented by each supervariable
+  members: Vec<Vec<usize>>,
+  nodes: Vec<Node>,
+  // Number of original variables that weren't eliminated yet
+  remaining: usize,
+  // Number of original variables represented by each supervariable
+  sizes: Vec<usize>,
+  // Adjacent variables of each variable
+  vars: Vec<Vec<usize>>,
+}
+
+impl Amd {
+  fn new(vars: Vec<Vec<usize>>) -> Self {
+    let len = vars.len();
+    let degrees: Vec<usize> = vars.iter().map(Vec::len).collect();
+    let heap =
+      degrees.iter().copied().enumerate().map(|(var, degree)| Reverse((degree, var))).collect();
+    Self {
+      degrees,
+      elem_vars: alloc::vec![Vec::new(); len],
+      elems: alloc::vec![Vec::new(); len],
+      ext: alloc::vec![(0, 0); len],
+      generation: 0,
+      heap,
+      marks: alloc::vec![0; len],
+      members: (0..len).map(|var| alloc::vec![var]).collect(),
+      nodes: alloc::vec![Node::Variable; len],
+      remaining: len,
+      sizes: alloc::vec![1; len],
+      vars,
+    }
+  }
+
+  // Weight of the variables of each element adjacent to `pivot_vars` that don't belong to
+  // `pivot_vars`.
+  fn compute_ext(&mut self, pivot_vars: &[usize]) -> Option<()> {
+    for var in pivot_vars.iter().copied() {
+      let size = at(&self.sizes, var)?;
+      let elems = take(self.elems.get_mut(var)?);
+      for elem in elems.iter().copied() {
+        if at(&self.nodes, elem)? != Node::Element {
+          continue;
+        }
+        if at(&self.ext, elem)?.0 != self.generation {
+          let mut elem_vars = take(self.elem_vars.get_mut(elem)?);
+          elem_vars.retain(|elem_var| self.nodes.get(*elem_var) == Some(&Node::Variable));
+          let weight = self.weight(&elem_vars)?;
+          *self.elem_vars.get_mut(elem)? = elem_vars;
+          *self.ext.get_mut(elem)? = (self.generation, weight);
+        }
+        let ext = &mut self.ext.get_mut(elem)?.1;
+        *ext = ext.saturating_sub(size);
+      }
+      *self.elems.get_mut(var)? = elems;
+    }
+    Some(())
+  }
+
+  // Merges variables of `pivot_vars` that have the same adjacent variables and elements.
+  fn detect_supervariables(&mut self, pivot_vars: &[usize]) -> Option<()> {
+    let mut hashes = Vec::with_capacity(pivot_vars.len());
+    for var in pivot_vars.iter().copied() {
+      let vars = self.vars.get_mut(var)?;
+      vars.sort_unstable();
+      let elems = self.elems.get_mut(var)?;
+      elems.sort_unstable();
+      let hash =
+        vars.iter().chain(self.elems.get(var)?).fold(0, |acc: usize, node| acc.wrapping_add(*node));
+      hashes.push((hash, var));
+    }
+    hashes.sort_unstable();
+    for group in hashes.chunk_by(|a, b| a.0 == b.0) {
+      for (idx, (_, var)) in group.iter().copied().enumerate() {
+        if at(&self.nodes, var)? != Node::Variable {
+          continue;
+        }
+        for (_, other) in group.get(idx.wrapping_add(1)..)?.iter().copied() {
+          let is_indistinguishable = at(&self.nodes, other)? == Node::Variable
+            && self.vars.get(var) == self.vars.get(other)
+            && self.elems.get(var) == self.elems.get(other);
+          if is_indistinguishable {
+            self.merge(var, other)?;
+          }
+        }
+      }
+    }
+    Some(())
+  }
+
+  // Turns `pivot` into an element, absorbing all of its adjacent elements, and updates the
+  // degrees of its variables.
+  fn eliminate(&mut self, pivot: usize) -> Option<()> {
+    self.generation = self.generation.checked_add(1)?;
+    self.remaining = self.remaining.checked_sub(at(&self.sizes, pivot)?)?;
+    self.mark(pivot)?;
+    *self.nodes.get_mut(pivot)? = Node::Element;
+    let mut candidates = take(self.vars.get_mut(pivot)?);
+    for elem in take(self.elems.get_mut(pivot)?) {
+      if at(&self.nodes, elem)? == Node::Element {
+        candidates.append(self.elem_vars.get_mut(elem)?);
+        *self.nodes.get_mut(elem)? = Node::Absorbed;
+      }
+    }
+    let mut pivot_vars = Vec::new();
+    for var in candidates {
+      if at(&self.nodes, var)? == Node::Variable && !self.is_marked(var) {
+        self.mark(var)?;
+        pivot_vars.push(var);
+      }
+    }
+    self.compute_ext(&pivot_vars)?;
+    let pivot_weight = self.weight(&pivot_vars)?;
+    for var in pivot_vars.iter().copied() {
+      self.update_var(pivot, var, pivot_weight)?;
+    }
+    self.detect_supervariables(&pivot_vars)?;
+    for var in pivot_vars.iter().copied() {
+      if at(&self.nodes, var)? == Node::Variable {
+        self.heap.push(Reverse((at(&self.degrees, var)?, var)));
+      }
+    }
+    *self.elem_vars.get_mut(pivot)? = pivot_vars;
+    Some(())
+  }
+
+  fn is_marked(&self, node: usize) -> bool {
+    self.marks.get(node) == Some(&self.generation)
+  }
+
+  fn mark(&mut self, node: usize) -> Option<()> {
+    *self.marks.get_mut(node)? = self.generation;
+    Some(())
+  }
+
+  fn merge(&mut self, var: usize, other: usize) -> Option<()> {
+    let other_size = take(self.sizes.get_mut(other)?);
+    let size = self.sizes.get_mut(var)?;
+    *size = size.checked_add(other_size)?;
+    let degree = self.degrees.get_mut(var)?;
+    *degree = degree.saturating_sub(other_size);
+    let mut other_members = take(self.members.get_mut(other)?);
+    self.members.get_mut(var)?.append(&mut other_members);
+    *self.nodes.get_mut(other)? = Node::Merged;
+    self.elems.get_mut(other)?.clear();
+    self.vars.get_mut(other)?.clear();
+    Some(())
+  }
+
+  // Prunes the adjacency of `var`, which belongs to the new element `pivot`, and computes its
+  // approximate external degree.
+  fn update_var(&mut self, pivot: usize, var: usize, pivot_weight: usize) -> Option<()> {
+    let size = at(&self.sizes, var)?;
+    let mut elems_ext: usize = 0;
+    let mut elems = take(self.elems.get_mut(var)?);
+    let mut idx = 0;
+    while let Some(elem) = elems.get(idx).copied() {
+      if at(&self.nodes, elem)? == Node::Element {
+        let ext = at(&self.ext, elem)?.1;
+        if ext > 0 {
+          elems_ext = elems_ext.saturating_add(ext);
+          idx = idx.wrapping_add(1);
+          continue;
+        }
+        // Aggressive absorption: all variables of `elem` belong to the new element.
+        *self.nodes.get_mut(elem)? = Node::Absorbed;
+      }
+      let _ = elems.swap_remove(idx);
+    }
+    elems.push(pivot);
+    *self.elems.get_mut(var)? = elems;
+    let mut vars = take(self.vars.get_mut(var)?);
+    vars.retain(|other| self.nodes.get(*other) == Some(&Node::Variable) && !self.is_marked(*other));
+    let vars_ext = self.weight(&vars)?;
+    *self.vars.get_mut(var)? = vars;
+    let pivot_ext = pivot_weight.saturating_sub(size);
+    let degree = self.degrees.get_mut(var)?;
+    *degree = self
+      .remaining
+      .saturating_sub(size)
+      .min(degree.saturating_add(pivot_ext))
+      .min(vars_ext.saturating_add(pivot_ext).saturating_add(elems_ext));
+    Some(())
+  }
+
+  fn weight(&self, vars: &[usize]) -> Option<usize> {
+    vars.iter().try_fold(0usize, |acc, var| acc.checked_add(at(&self.sizes, *var)?))
+  }
+}
+
+fn at<T>(slice: &[T], idx: usize) -> Option<T>
+where
+  T: Copy,
+{
+  slice.get(idx).copied()
+}
@@ -0,0 +1,171 @@
+use crate::csl::{
+  csl_utils::{balanced_split, outermost_line_by_off, outermost_offs},
+  CslError, CslMut, CslRef,
+};
+use core::{mem, ops::Range};
+
+/// How the outermost lines are grouped into chunks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ChunkLen {
+  /// Fixed number of outermost lines per chunk, except for the last chunk.
+  Lines(usize),
+  /// Fixed number of chunks with approximately the same number of non-zero elements.
+  Nnz(usize),
+}
+
+macro_rules! impl_iter {
+  ($csl_iter:ident, $data_type:ty, $split_at_checked:ident, $ref:ident) => {
+    /// Iterator of contiguous chunks of outermost lines of a CSL.
+    ///
+    /// Chunks that don't contain any line have all their dimensions set to zero.
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct $csl_iter<'slices, T, const D: usize> {
+      chunk_len: ChunkLen,
+      curr_idx: usize,
+      data: $data_type,
+      dims: [usize; D],
+      indcs: &'slices [usize],
+      max_idx: usize,
+      offs: &'slices [usize],
+    }
+
+    impl<'slices, T, const D: usize> $csl_iter<'slices, T, D> {
+      pub(crate) fn new(
+        chunk_len: ChunkLen,
+        dims: [usize; D],
+        data: $data_type,
+        indcs: &'slices [usize],
+        offs: &'slices [usize],
+      ) -> crate::Result<Self> {
+        let lines = *dims.first().ok_or(CslError::InvalidIterDim)?;
+        let (normalized, max_idx) = match chunk_len {
+          ChunkLen::Lines(0) | ChunkLen::Nnz(0) => return Err(CslError::InvalidChunks.into()),
+          ChunkLen::Lines(len) => (chunk_len, lines.div_ceil(len)),
+          ChunkLen::Nnz(chunks) => {
+            let max_idx = chunks.min(lines);
+            (ChunkLen::Nnz(max_idx), max_idx)
+          }
+        };
+        Ok(Self { chunk_len: normalized, curr_idx: 0, data, dims, indcs, max_idx, offs })
+      }
+
+      pub(crate) fn split_at(self, idx: usize) -> Option<[Self; 2]> {
+        let cut_point = self.curr_idx.checked_add(idx)?;
+        let range = self.boundary(self.curr_idx)..self.boundary(cut_point);
+        let [_, values] = outermost_offs(&self.dims, self.offs, range);
+        let values_diff = values.end.checked_sub(values.start)?;
+        let (data_head, data_tail) = self.data.$split_at_checked(values_diff)?;
+        let (indcs_head, indcs_tail) = self.indcs.split_at_checked(values_diff)?;
+        Some([
+          $csl_iter {
+            chunk_len: self.chunk_len,
+            curr_idx: self.curr_idx,
+            data: data_head,
+            dims: self.dims,
+            indcs: indcs_head,
+            max_idx: cut_point,
+            offs: self.offs,
+          },
+          $csl_iter {
+            chunk_len: self.chunk_len,
+            curr_idx: cut_point,
+            data: data_tail,
+            dims: self.dims,
+            indcs: indcs_tail,
+            max_idx: self.max_idx,
+            offs: self.offs,
+          },
+        ])
+      }
+
+      // Index of the first outermost line of the chunk located at `idx`.
+      fn boundary(&self, idx: usize) -> usize {
+        let lines = self.dims.first().copied().unwrap_or(0);
+        match self.chunk_len {
+          ChunkLen::Lines(len) => len.saturating_mul(idx).min(lines),
+          ChunkLen::Nnz(chunks) => {
+            if idx >= chunks {
+              return lines;
+            }
+            let [_, values] = outermost_offs(&self.dims, self.offs, 0..lines);
+            let nnz = values.end.saturating_sub(values.start);
+            let target = values.start.saturating_add(balanced_split(nnz, chunks, idx));
+            outermost_line_by_off(&self.dims, self.offs, lines, target)
+          }
+        }
+      }
+
+      // Dimensions, offsets and number of non-zero elements of the chunk located at `idx`.
+      fn chunk(&self, idx: usize) -> Option<([usize; D], Range<usize>, usize)> {
+        let range = self.boundary(idx)..self.boundary(idx.checked_add(1)?);
+        let mut dims = self.dims;
+        match dims.first_mut() {
+          Some(first) if range.end > range.start => *first = range.end.wrapping_sub(range.start),
+          _ => dims = [0; D],
+        }
+        let [offs_range, values] = outermost_offs(&self.dims, self.offs, range);
+        Some((dims, offs_range, values.end.checked_sub(values.start)?))
+      }
+    }
+
+    impl<T, const D: usize> DoubleEndedIterator for $csl_iter<'_, T, D> {
+      #[inline]
+      fn next_back(&mut self) -> Option<Self::Item> {
+        if self.curr_idx >= self.max_idx {
+          return None;
+        }
+        let max_idx_less_one = self.max_idx.checked_sub(1)?;
+        let (dims, offs_range, values_diff) = self.chunk(max_idx_less_one)?;
+        self.max_idx = max_idx_less_one;
+        let data = mem::take(&mut self.data);
+        let data_cut_point = data.len().checked_sub(values_diff)?;
+        let indcs_cut_point = self.indcs.len().checked_sub(values_diff)?;
+        let (data_head, data_tail) = data.$split_at_checked(data_cut_point)?;
+        let (indcs_head, indcs_tail) = self.indcs.split_at_checked(indcs_cut_point)?;
+        self.data = data_head;
+        self.indcs = indcs_head;
+        Some($ref {
+          data: data_tail,
+          dims: dims.into(),
+          indcs: indcs_tail,
+          offs: self.offs.get(offs_range)?,
+        })
+      }
+    }
+
+    impl<T, const D: usize> ExactSizeIterator for $csl_iter<'_, T, D> {}
+
+    impl<'slices, T, const D: usize> Iterator for $csl_iter<'slices, T, D> {
+      type Item = $ref<'slices, T, D>;
+
+      #[inline]
+      fn next(&mut self) -> Option<Self::Item> {
+        if self.curr_idx >= self.max_idx {
+          return None;
+        }
+        let (dims, offs_range, values_diff) = self.chunk(self.curr_idx)?;
+        self.curr_idx = self.curr_idx.checked_add(1)?;
+        let data = mem::take(&mut self.data);
+        let (data_head, data_tail) = data.$split_at_checked(values_diff)?;
+        let (indcs_head, indcs_tail) = self.indcs.split_at_checked(values_diff)?;
+        self.data = data_tail;
+        self.indcs = indcs_tail;
+        Some($ref {
+          data: data_head,
+          dims: dims.into(),
+          indcs: indcs_head,
+          offs: self.offs.get(offs_range)?,
+        })
+      }
+
+      #[inline]
+      fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.max_idx.saturating_sub(self.curr_idx);
+        (len, Some(len))
+      }
+    }
+  };
+}
+
+impl_iter!(CslChunkIterMut, &'slices mut [T], split_at_mut_checked, CslMut);
+impl_iter!(CslChunkIterRef, &'slices [T], split_at_checked, CslRef);
@@ -0,0 +1,180 @@
+use crate::{
+  csl::Csl,
+  dense::{Dense, DenseError},
+};
+#[cfg(feature = "alloc")]
+use crate::{csl::CslVec, dense::DenseVec};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+#[cfg(feature = "alloc")]
+use core::ops::{Add, Mul};
+
+impl<DATA, DS, IS, OS, const D: usize> Csl<DS, IS, OS, D>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Element-wise sum with a dense instance of the same dimensions, which results in a new
+  /// dense instance.
+  ///
+  /// # Arguments
+  ///
+  /// * `dense`: Dense instance with the same dimensions of `self`
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::{csl::CslArray, dense::DenseArray};
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let dense = DenseArray::new([2, 3], [10, 20, 30, 40, 50, 60]).unwrap();
+  /// assert_eq!(csl.add_dense(&dense).unwrap().data(), &[11, 20, 32, 40, 53, 60]);
+  /// assert_eq!((&csl + &dense).unwrap(), csl.add_dense(&dense).unwrap());
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn add_dense<DDS>(&self, dense: &Dense<DDS, D>) -> crate::Result<DenseVec<DATA, D>>
+  where
+    DATA: Add<Output = DATA> + Copy,
+    DDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    if &self.dims.0 != dense.dims() {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    let mut rslt = DenseVec::new(*dense.dims(), dense.data().to_vec())?;
+    for (indcs, elem) in self.iter_nnz() {
+      let value = rslt.value_mut(indcs).ok_or(DenseError::IncompatibleDims)?;
+      *value = *value + *elem;
+    }
+    Ok(rslt)
+  }
+
+  /// Element-wise product with a dense instance of the same dimensions, which results in a new
+  /// CSL with the same sparsity pattern of `self`.
+  ///
+  /// Only the dense elements located at stored positions are sampled, which is the building
+  /// block of the SDDMM (Sampled Dense-Dense Matrix Multiplication) kernel.
+  ///
+  /// # Arguments
+  ///
+  /// * `dense`: Dense instance with the same dimensions of `self`
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::{csl::CslArray, dense::DenseArray};
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let dense = DenseArray::new([2, 3], [10, 20, 30, 40, 50, 60]).unwrap();
+  /// let rslt = csl.mul_dense(&dense).unwrap();
+  /// assert_eq!(rslt.data(), &[10, 60, 150]);
+  /// assert_eq!((rslt.indcs(), rslt.offs()), (csl.indcs(), csl.offs()));
+  /// assert_eq!((&csl * &dense).unwrap(), rslt);
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn mul_dense<DDS>(&self, dense: &Dense<DDS, D>) -> crate::Result<CslVec<DATA, D>>
+  where
+    DATA: Copy + Mul<Output = DATA>,
+    DDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    if &self.dims.0 != dense.dims() {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    let data = self
+      .iter_nnz()
+      .map(|(indcs, elem)| Some(*elem * *dense.value(indcs)?))
+      .collect::<Option<Vec<_>>>()
+      .ok_or(DenseError::IncompatibleDims)?;
+    let offs = self.offs.as_ref();
+    let first_off = offs.first().copied().unwrap_or(0);
+    CslVec::new(
+      self.dims.0,
+      data,
+      self.indcs.as_ref().to_vec(),
+      offs.iter().map(|off| off.saturating_sub(first_off)).collect(),
+    )
+  }
+}
+
+impl<DATA, DS, IS, OS, const D: usize> Csl<DS, IS, OS, D>
+where
+  DS: AsMut<[DATA]> + AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Overwrites all stored elements with the elements of `dense` that are located at the same
+  /// positions. The sparsity pattern of `self` is kept as is.
+  ///
+  /// # Arguments
+  ///
+  /// * `dense`: Dense instance with the same dimensions of `self`
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::CslArray, dense::DenseArray};
+  /// let mut csl = CslArray::new([2, 3], [0; 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let dense = DenseArray::new([2, 3], [10, 20, 30, 40, 50, 60]).unwrap();
+  /// csl.gather(&dense).unwrap();
+  /// assert_eq!(csl.data(), &[10, 30, 50]);
+  /// ```
+  #[inline]
+  pub fn gather<DDS>(&mut self, dense: &Dense<DDS, D>) -> crate::Result<()>
+  where
+    DATA: Clone,
+    DDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    if &self.dims.0 != dense.dims() {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    for (indcs, elem) in self.iter_nnz_mut() {
+      *elem = dense.value(indcs).ok_or(DenseError::IncompatibleDims)?.clone();
+    }
+    Ok(())
+  }
+}
+
+/// See [`Csl::add_dense`].
+#[cfg(feature = "alloc")]
+impl<DATA, DDS, DS, IS, OS, const D: usize> Add<&Dense<DDS, D>> for &Csl<DS, IS, OS, D>
+where
+  DATA: Add<Output = DATA> + Copy,
+  DDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  type Output = crate::Result<DenseVec<DATA, D>>;
+
+  #[inline]
+  fn add(self, rhs: &Dense<DDS, D>) -> Self::Output {
+    self.add_dense(rhs)
+  }
+}
+
+/// See [`Csl::mul_dense`].
+#[cfg(feature = "alloc")]
+impl<DATA, DDS, DS, IS, OS, const D: usize> Mul<&Dense<DDS, D>> for &Csl<DS, IS, OS, D>
+where
+  DATA: Copy + Mul<Output = DATA>,
+  DDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  type Output = crate::Result<CslVec<DATA, D>>;
+
+  #[inline]
+  fn mul(self, rhs: &Dense<DDS, D>) -> Self::Output {
+    self.mul_dense(rhs)
+  }
+}
@@ -32,6 +32,16 @@ pub enum CslError {
   /// ```
   DuplicatedIndices,
 
+  /// A symmetric matrix has an element outside of its stored triangle
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, CslError, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2], [0, 1], [0, 2, 2]).unwrap();
+  /// let sym = SymCsl::new(csl, Triangle::Lower);
+  /// assert_eq!(sym, Err(ndstruct::Error::Csl(CslError::ElementOutsideTriangle)));
+  /// ```
+  ElementOutsideTriangle,
+
   /// A index is greater or equal to the innermost dimension length
   ///
   /// ```rust
@@ -51,6 +61,25 @@ pub enum CslError {
   /// ```
   InnermostDimsZero,
 
+  /// The axis doesn't refer to a dimension other than the innermost dimension
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::{csl::CslError, doc_tests::csl_vec_4};
+  /// let csl = csl_vec_4();
+  /// assert_eq!(csl.axis_line_iter(3), Err(ndstruct::Error::Csl(CslError::InvalidAxis)));
+  /// ```
+  InvalidAxis,
+
+  /// Chunk iterators must deal with a non-zero number of chunks or lines per chunk
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::{csl::CslError, doc_tests::csl_vec_4};
+  /// let csl = csl_vec_4();
+  /// assert_eq!(csl.par_chunks(0).err(), Some(ndstruct::Error::Csl(CslError::InvalidChunks)));
+  /// ```
+  #[cfg(feature = "rayon")]
+  InvalidChunks,
+
   /// Line iterator must deal with non-empty dimensions
   #[cfg_attr(feature = "alloc", doc = "```rust")]
   #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
@@ -60,6 +89,17 @@ pub enum CslError {
   /// ```
   InvalidIterDim,
 
+  /// The range of lines isn't contained in the outermost dimension
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::{csl::CslError, doc_tests::csl_vec_4};
+  /// let mut csl = csl_vec_4();
+  /// assert_eq!(csl.drain_lines(1..9), Err(ndstruct::Error::Csl(CslError::InvalidLineRange)));
+  /// ```
+  #[cfg(feature = "alloc")]
+  InvalidLineRange,
+
   /// Offsets length is different than the dimensions product
   /// (without the innermost dimension) plus one.
   /// This rule doesn't not apply to an empty dimension.
@@ -80,6 +120,58 @@ pub enum CslError {
   /// ```
   InvalidOffsetsOrder,
 
+  /// A permutation doesn't contain every index of its dimension exactly once
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, CslError};
+  /// let csl = CslArray::new([2, 2], [1, 2], [0, 1], [0, 1, 2]).unwrap();
+  /// assert_eq!(csl.permute(&[0, 0], &[0, 1]), Err(ndstruct::Error::Csl(CslError::InvalidPermutation)));
+  /// ```
+  #[cfg(feature = "alloc")]
+  InvalidPermutation,
+
+  /// A probability must be within `[0, 1]`
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::csl::{CslError, CslRndBuilder, CslVec};
+  /// use rand::rngs::mock::StepRng;
+  /// let rslt: ndstruct::Result<CslVec<u8, 2>> =
+  ///   CslRndBuilder::new([2, 2]).erdos_renyi(2.0).build(&mut StepRng::new(0, 1), |_, _| 1);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Csl(CslError::InvalidProbability)));
+  /// ```
+  #[cfg(feature = "rand")]
+  InvalidProbability,
+
+  /// The new dimensions don't describe the same number of lines of the current dimensions
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::CslError, doc_tests::csl_array_4};
+  /// let csl = csl_array_4().reshape([5, 5]);
+  /// assert_eq!(csl, Err(ndstruct::Error::Csl(CslError::InvalidReshapeDims)));
+  /// ```
+  InvalidReshapeDims,
+
+  /// Reshaping can't modify the innermost dimension
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::CslError, doc_tests::csl_array_4};
+  /// let csl = csl_array_4().reshape([120]);
+  /// assert_eq!(csl, Err(ndstruct::Error::Csl(CslError::InvalidReshapeInnermostDim)));
+  /// ```
+  InvalidReshapeInnermostDim,
+
+  /// The length of a vector doesn't match the corresponding dimension of a matrix
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, CslError, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2], [0, 0], [0, 1, 2]).unwrap();
+  /// let sym = SymCsl::new(csl, Triangle::Lower).unwrap();
+  /// let rslt = sym.spmv(&[1, 2, 3], &mut [0, 0]);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Csl(CslError::InvalidVectorLength)));
+  /// ```
+  InvalidVectorLength,
+
   /// Last offset is not equal to the nnz
   ///
   /// ```rust
@@ -103,6 +195,16 @@ pub enum CslError {
   #[cfg(feature = "rand")]
   NnzGreaterThanMaximumNnz,
 
+  /// The operation requires a matrix with the same number of rows and columns
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::csl::{CslArray, CslError};
+  /// let csl = CslArray::new([1, 2], [1], [1], [0, 1]).unwrap();
+  /// assert_eq!(csl.reverse_cuthill_mckee(), Err(ndstruct::Error::Csl(CslError::NonSquareMatrix)));
+  /// ```
+  NonSquareMatrix,
+
   /// It isn't possible to have more lines than [`usize::MAX`] - 2
   ///
   /// ```rust
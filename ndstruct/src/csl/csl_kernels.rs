@@ -0,0 +1,494 @@
+#[cfg(feature = "rayon")]
+use crate::csl::csl_utils::balanced_split;
+#[cfg(feature = "simd")]
+use crate::simd::SimdFloat;
+#[cfg(feature = "alloc")]
+use crate::{csl::CslVec, num::Conjugate};
+use crate::{
+  csl::{Csl, CslError},
+  num::{Num, Zero},
+  utils::windows2,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+use core::ops::Add;
+#[cfg(feature = "rayon")]
+use core::ops::Range;
+
+// Number of lines plus elements of each part used by parallel kernels. Also makes the order of
+// the operations independent of the number of threads.
+#[cfg(all(feature = "alloc", feature = "rayon"))]
+const PAR_MERGE_PATH_ITEMS: usize = 4096;
+
+/// Contiguous range of stored elements of a CSL delimited by a merge path partition.
+///
+/// Lines and elements are consumed together, which means that parts have approximately the same
+/// amount of work regardless of the distribution of elements and that a line can be shared by
+/// several consecutive parts.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CslMergePathPart<'slices, T> {
+  data: &'slices [T],
+  end: [usize; 2],
+  indcs: &'slices [usize],
+  offs: &'slices [usize],
+  start: [usize; 2],
+}
+
+#[cfg(feature = "rayon")]
+impl<'slices, T> CslMergePathPart<'slices, T> {
+  pub(crate) fn new(
+    data: &'slices [T],
+    indcs: &'slices [usize],
+    offs: &'slices [usize],
+    parts: usize,
+    part: usize,
+  ) -> Self {
+    let lines = offs.len().saturating_sub(1);
+    let total = lines.saturating_add(data.len());
+    let start = merge_path_coords(offs, balanced_split(total, parts, part));
+    let end = merge_path_coords(offs, balanced_split(total, parts, part.saturating_add(1)));
+    let range = start[1]..end[1];
+    Self {
+      data: data.get(range.clone()).unwrap_or_default(),
+      end,
+      indcs: indcs.get(range).unwrap_or_default(),
+      offs,
+      start,
+    }
+  }
+
+  /// Stored elements of this part.
+  #[inline]
+  pub fn data(&self) -> &'slices [T] {
+    self.data
+  }
+
+  /// Lines whose last element, if any, belongs to this part.
+  #[inline]
+  pub fn ended_lines(&self) -> Range<usize> {
+    self.start[0]..self.end[0]
+  }
+
+  /// Innermost indices of the stored elements of this part.
+  #[inline]
+  pub fn indcs(&self) -> &'slices [usize] {
+    self.indcs
+  }
+
+  /// Iterator that returns the index, the elements and the innermost indices of all
+  /// [`ended_lines`](#method.ended_lines) followed by the line that continues in the next part,
+  /// if it has elements in this part.
+  ///
+  /// The first and the last returned lines can be partial.
+  #[inline]
+  pub fn lines(&self) -> impl Iterator<Item = (usize, &'slices [T], &'slices [usize])> {
+    let Self { data, end, indcs, offs, start } = *self;
+    let first_off = offs.first().copied().unwrap_or(0);
+    let rel_off = move |idx: usize| offs.get(idx).map_or(0, |off| off.saturating_sub(first_off));
+    let lines_end = if rel_off(end[0]) < end[1] { end[0].saturating_add(1) } else { end[0] };
+    (start[0]..lines_end).map(move |line| {
+      let line_start = rel_off(line).max(start[1]).saturating_sub(start[1]);
+      let line_end = rel_off(line.saturating_add(1)).min(end[1]).saturating_sub(start[1]);
+      let range = line_start..line_end;
+      (line, data.get(range.clone()).unwrap_or_default(), indcs.get(range).unwrap_or_default())
+    })
+  }
+}
+
+impl<DATA, DS, IS, OS, const D: usize> Csl<DS, IS, OS, D>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Sum of the stored elements of each line.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// assert!(csl_array_4().line_sums().take(5).eq([3, 3, 0, 9, 6]));
+  /// ```
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn line_sums<'csl>(&'csl self) -> impl Iterator<Item = DATA> + 'csl
+  where
+    DATA: Add<Output = DATA> + Copy + Zero + 'csl,
+  {
+    let first_off = self.offs.as_ref().first().copied().unwrap_or(0);
+    let data = self.data.as_ref();
+    windows2(self.offs.as_ref()).map(move |[a, b]| {
+      let range = a.saturating_sub(first_off)..b.saturating_sub(first_off);
+      data.get(range).unwrap_or_default().iter().fold(DATA::zero(), |acc, elem| acc + *elem)
+    })
+  }
+
+  /// Parallel iterator that splits all lines and stored elements into up to `parts`
+  /// [`CslMergePathPart`]s using `rayon`.
+  ///
+  /// Unlike [`par_nnz_balanced_chunks`](#method.par_nnz_balanced_chunks), lines can be split
+  /// across parts, which keeps the work balanced even for lines with a huge number of elements.
+  ///
+  /// # Arguments
+  ///
+  /// * `parts`: Number of parts
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslArray;
+  /// use rayon::prelude::*;
+  /// // [1, 2, 3, 4, 5, 6]
+  /// // [_, _, _, _, _, _]
+  /// // [_, 7, _, _, _, _]
+  /// let csl = CslArray::new([3, 6], [1, 2, 3, 4, 5, 6, 7], [0, 1, 2, 3, 4, 5, 1], [0, 6, 6, 7])?;
+  /// let parts: Vec<_> = csl.par_merge_path_iter(2)?.collect();
+  /// assert_eq!(parts[0].data(), &[1, 2, 3, 4, 5]);
+  /// assert_eq!(parts[0].ended_lines(), 0..0);
+  /// assert_eq!(parts[1].data(), &[6, 7]);
+  /// assert_eq!(parts[1].ended_lines(), 0..3);
+  /// assert!(parts[1].lines().map(|(line, data, _)| (line, data)).eq([
+  ///   (0, &[6][..]),
+  ///   (1, &[][..]),
+  ///   (2, &[7][..])
+  /// ]));
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_merge_path_iter<'csl>(
+    &'csl self,
+    parts: usize,
+  ) -> crate::Result<impl rayon::iter::IndexedParallelIterator<Item = CslMergePathPart<'csl, DATA>>>
+  where
+    DATA: Sync + 'csl,
+  {
+    use rayon::prelude::*;
+    if parts == 0 {
+      return Err(CslError::InvalidChunks.into());
+    }
+    let data = self.data.as_ref();
+    let [indcs, offs] = [self.indcs.as_ref(), self.offs.as_ref()];
+    Ok(
+      (0..parts)
+        .into_par_iter()
+        .map(move |part| CslMergePathPart::new(data, indcs, offs, parts, part)),
+    )
+  }
+
+  /// Parallel version of [`line_sums`](#method.line_sums) using `rayon`.
+  ///
+  /// Work is split by merge path partitions, see
+  /// [`par_merge_path_iter`](#method.par_merge_path_iter).
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// let csl = csl_array_4();
+  /// assert_eq!(csl.par_line_sums(), Ok(csl.line_sums().collect()));
+  /// ```
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[inline]
+  pub fn par_line_sums(&self) -> crate::Result<Vec<DATA>>
+  where
+    DATA: Add<Output = DATA> + Copy + Send + Sync + Zero,
+  {
+    let mut rslt = alloc::vec![DATA::zero(); self.offs.as_ref().len().saturating_sub(1)];
+    self.par_reduce_lines(&mut rslt, |value, _| *value)?;
+    Ok(rslt)
+  }
+
+  // Writes into `y` the sum of `cb` applied to every element of each line.
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  fn par_reduce_lines<R>(
+    &self,
+    y: &mut [R],
+    cb: impl Fn(&DATA, usize) -> R + Sync,
+  ) -> crate::Result<()>
+  where
+    DATA: Sync,
+    R: Add<Output = R> + Copy + Send + Zero,
+  {
+    use rayon::prelude::*;
+    let data = self.data.as_ref();
+    let [indcs, offs] = [self.indcs.as_ref(), self.offs.as_ref()];
+    if y.len() != offs.len().saturating_sub(1) {
+      return Err(CslError::InvalidVectorLength.into());
+    }
+    let parts = y.len().saturating_add(data.len()).div_ceil(PAR_MERGE_PATH_ITEMS).max(1);
+    let mut y_parts = Vec::with_capacity(parts);
+    let mut y_rest = &mut *y;
+    for part in 0..parts {
+      let merge_path_part = CslMergePathPart::new(data, indcs, offs, parts, part);
+      let (y_head, y_tail) = y_rest
+        .split_at_mut_checked(merge_path_part.ended_lines().len())
+        .ok_or(crate::Error::UnknownError)?;
+      y_parts.push((merge_path_part, y_head));
+      y_rest = y_tail;
+    }
+    let carries: Vec<_> = y_parts
+      .into_par_iter()
+      .map(|(merge_path_part, y_part)| {
+        let mut carry = None;
+        for (line, line_data, line_indcs) in merge_path_part.lines() {
+          let iter = line_data.iter().zip(line_indcs);
+          let sum = iter.fold(R::zero(), |acc, (value, idx)| acc + cb(value, *idx));
+          match y_part.get_mut(line.wrapping_sub(merge_path_part.start[0])) {
+            Some(elem) => *elem = sum,
+            None => carry = Some((line, sum)),
+          }
+        }
+        carry
+      })
+      .collect();
+    for (line, carry) in carries.into_iter().flatten() {
+      if let Some(elem) = y.get_mut(line) {
+        *elem = *elem + carry;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl<DATA, DS, IS, OS> Csl<DS, IS, OS, 2>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Conjugate transpose, also known as Hermitian transpose, i.e., the transpose where every
+  /// element is replaced by its complex conjugate.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(all(feature = "alloc", feature = "complex"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "complex")), doc = "```ignore")]
+  /// use ndstruct::{csl::{CslArray, CslVec}, num::Complex};
+  /// // [1+2i, _   ]
+  /// // [3-1i, 4+0i]
+  /// let data = [Complex::new(1, 2), Complex::new(3, -1), Complex::new(4, 0)];
+  /// let csl = CslArray::new([2, 2], data, [0, 0, 1], [0, 1, 3]).unwrap();
+  /// let data = vec![Complex::new(1, -2), Complex::new(3, 1), Complex::new(4, 0)];
+  /// assert_eq!(
+  ///   csl.conj_transpose(),
+  ///   CslVec::new([2, 2], data, vec![0, 1, 1], vec![0, 2, 3])
+  /// );
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[inline]
+  pub fn conj_transpose(&self) -> crate::Result<CslVec<DATA, 2>>
+  where
+    DATA: Conjugate,
+  {
+    self.do_transpose(Conjugate::conj)
+  }
+
+  /// Parallel version of [`spmv`](#method.spmv) using `rayon`.
+  ///
+  /// Work is split by merge path partitions, see
+  /// [`par_merge_path_iter`](#method.par_merge_path_iter), which keeps all threads busy even
+  /// for matrices with a few dense rows.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::csl::CslArray;
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let mut y = [0; 2];
+  /// csl.par_spmv(&[1, 10, 100], &mut y).unwrap();
+  /// assert_eq!(y, [201, 30]);
+  /// ```
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn par_spmv(&self, x: &[DATA], y: &mut [DATA]) -> crate::Result<()>
+  where
+    DATA: Num + Send + Sync,
+  {
+    let [rows, cols] = self.dims.0;
+    if x.len() != cols || y.len() != rows {
+      return Err(CslError::InvalidVectorLength.into());
+    }
+    self.par_reduce_lines(y, |value, idx| *value * x.get(idx).copied().unwrap_or_else(DATA::zero))
+  }
+
+  /// Sparse matrix-vector multiplication that writes `self * x` into `y`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::CslArray;
+  /// // [_, _, 1]
+  /// // [_, 3, _]
+  /// let csl = CslArray::new([2, 3], [1, 3], [2, 1], [0, 1, 2]).unwrap();
+  /// let mut y = [0; 2];
+  /// csl.spmv(&[1, 10, 100], &mut y).unwrap();
+  /// assert_eq!(y, [100, 30]);
+  /// ```
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn spmv(&self, x: &[DATA], y: &mut [DATA]) -> crate::Result<()>
+  where
+    DATA: Num,
+  {
+    let [rows, cols] = self.dims.0;
+    if x.len() != cols || y.len() != rows {
+      return Err(CslError::InvalidVectorLength.into());
+    }
+    let first_off = self.offs.as_ref().first().copied().unwrap_or(0);
+    let (data, indcs) = (self.data.as_ref(), self.indcs.as_ref());
+    for ([a, b], y_elem) in windows2(self.offs.as_ref()).zip(y.iter_mut()) {
+      let range = a.saturating_sub(first_off)..b.saturating_sub(first_off);
+      let iter = data.get(range.clone()).unwrap_or_default().iter();
+      *y_elem =
+        iter.zip(indcs.get(range).unwrap_or_default()).fold(DATA::zero(), |acc, (value, idx)| {
+          acc + *value * x.get(*idx).copied().unwrap_or_else(DATA::zero)
+        });
+    }
+    Ok(())
+  }
+
+  /// Transpose, i.e., the element located at `[row, col]` is moved to `[col, row]`.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::csl::{CslArray, CslVec};
+  /// // [_, 1, 2]
+  /// // [3, _, _]
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [1, 2, 0], [0, 2, 3]).unwrap();
+  /// assert_eq!(
+  ///   csl.transpose(),
+  ///   CslVec::new([3, 2], vec![3, 1, 2], vec![1, 0, 0], vec![0, 1, 2, 3])
+  /// );
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[inline]
+  pub fn transpose(&self) -> crate::Result<CslVec<DATA, 2>>
+  where
+    DATA: Clone,
+  {
+    self.do_transpose(Clone::clone)
+  }
+
+  /// Vectorized version of [`spmv`](#method.spmv) for `f32` and `f64`.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(feature = "simd", doc = "```rust")]
+  #[cfg_attr(not(feature = "simd"), doc = "```ignore")]
+  /// use ndstruct::csl::CslArray;
+  /// let csl = CslArray::new([2, 3], [1.0, 2.0, 3.0], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let [mut scalar, mut simd] = [[0.0; 2]; 2];
+  /// csl.spmv(&[1.0, 10.0, 100.0], &mut scalar).unwrap();
+  /// csl.simd_spmv(&[1.0, 10.0, 100.0], &mut simd).unwrap();
+  /// assert_eq!(scalar, simd);
+  ///
+  /// // Lines that are longer than the vectors of all instruction sets
+  /// let data: [f64; 38] = core::array::from_fn(|idx| f64::from(u8::try_from(idx).unwrap()));
+  /// let indcs: [usize; 38] = core::array::from_fn(|idx| if idx < 19 { idx } else { idx - 18 });
+  /// let csl = CslArray::new([2, 20], data, indcs, [0, 19, 38]).unwrap();
+  /// let x: [f64; 20] = core::array::from_fn(|idx| f64::from(u8::try_from(idx).unwrap()));
+  /// let [mut scalar, mut simd] = [[0.0; 2]; 2];
+  /// csl.spmv(&x, &mut scalar).unwrap();
+  /// csl.simd_spmv(&x, &mut simd).unwrap();
+  /// assert_eq!(scalar, simd);
+  /// ```
+  #[cfg(feature = "simd")]
+  #[inline]
+  pub fn simd_spmv(&self, x: &[DATA], y: &mut [DATA]) -> crate::Result<()>
+  where
+    DATA: SimdFloat,
+  {
+    let [rows, cols] = self.dims.0;
+    if x.len() != cols || y.len() != rows {
+      return Err(CslError::InvalidVectorLength.into());
+    }
+    let first_off = self.offs.as_ref().first().copied().unwrap_or(0);
+    let (data, indcs) = (self.data.as_ref(), self.indcs.as_ref());
+    for ([a, b], y_elem) in windows2(self.offs.as_ref()).zip(y.iter_mut()) {
+      let range = a.saturating_sub(first_off)..b.saturating_sub(first_off);
+      let line_data = data.get(range.clone()).unwrap_or_default();
+      *y_elem = DATA::sparse_dot(line_data, indcs.get(range).unwrap_or_default(), x);
+    }
+    Ok(())
+  }
+
+  // Counting sort of all elements by their innermost indices, which become the new lines.
+  #[cfg(feature = "alloc")]
+  fn do_transpose(&self, cb: impl Fn(&DATA) -> DATA) -> crate::Result<CslVec<DATA, 2>> {
+    let [rows, cols] = self.dims.0;
+    let (data, indcs, offs) = (self.data.as_ref(), self.indcs.as_ref(), self.offs.as_ref());
+    let first_off = offs.first().copied().unwrap_or(0);
+    let mut new_offs: Vec<usize> = alloc::vec![0; cols.saturating_add(1)];
+    for idx in indcs {
+      let elem =
+        new_offs.get_mut(idx.saturating_add(1)).ok_or(CslError::IndcsGreaterThanEqualDimLength)?;
+      *elem = elem.saturating_add(1);
+    }
+    let mut acc: usize = 0;
+    for off in &mut new_offs {
+      acc = acc.saturating_add(*off);
+      *off = acc;
+    }
+    let mut next_positions = new_offs.clone();
+    let mut new_indcs = alloc::vec![0; indcs.len()];
+    let mut order = alloc::vec![0; indcs.len()];
+    for (row, [a, b]) in windows2(offs).enumerate() {
+      for pos in a.saturating_sub(first_off)..b.saturating_sub(first_off) {
+        let idx = indcs.get(pos).ok_or(crate::Error::UnknownError)?;
+        let next_position = next_positions.get_mut(*idx).ok_or(crate::Error::UnknownError)?;
+        *new_indcs.get_mut(*next_position).ok_or(crate::Error::UnknownError)? = row;
+        *order.get_mut(*next_position).ok_or(crate::Error::UnknownError)? = pos;
+        *next_position = next_position.saturating_add(1);
+      }
+    }
+    let new_data: Vec<DATA> = order
+      .iter()
+      .map(|pos| data.get(*pos).map(&cb))
+      .collect::<Option<_>>()
+      .ok_or(crate::Error::UnknownError)?;
+    if rows == 0 {
+      return CslVec::new([0, 0], new_data, new_indcs, alloc::vec![0]);
+    }
+    CslVec::new([cols, rows], new_data, new_indcs, new_offs)
+  }
+}
+
+// Coordinates, i.e., the number of consumed lines and elements, of the intersection between
+// `diagonal` and the merge path of the line ends and the element positions.
+#[cfg(feature = "rayon")]
+fn merge_path_coords(offs: &[usize], diagonal: usize) -> [usize; 2] {
+  let first_off = offs.first().copied().unwrap_or(0);
+  let lines = offs.len().saturating_sub(1);
+  let nnz = offs.last().copied().unwrap_or(0).saturating_sub(first_off);
+  let mut low = diagonal.saturating_sub(nnz);
+  let mut high = diagonal.min(lines);
+  while low < high {
+    let mid = low.saturating_add(high.wrapping_sub(low) / 2);
+    let line_end = offs.get(mid.saturating_add(1)).map_or(0, |off| off.saturating_sub(first_off));
+    if line_end < diagonal.wrapping_sub(mid) {
+      low = mid.saturating_add(1);
+    } else {
+      high = mid;
+    }
+  }
+  [low, diagonal.saturating_sub(low)]
+}
@@ -1,9 +1,18 @@
-use crate::csl::{csl_utils::manage_last_offset, Csl};
+use crate::csl::{
+  csl_utils::{correct_offs_len, manage_last_offset},
+  Csl,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use cl_aux::{Push, SingleTypeStorage};
 use core::fmt::{Debug, Display, Formatter};
 
 /// Constructs valid lines in a easy and interactive manner, abstracting away the complexity
 /// of the compressed sparse format.
+///
+/// Lines are constructed from the first to the last and the number of lines can't exceed the
+/// number of lines described by the current dimensions. Call [`finish`](#method.finish) to
+/// verify that the constructed instance is valid.
 #[derive(Debug, Eq, PartialEq)]
 pub struct CslLineConstructor<'csl, DS, IS, OS, const D: usize> {
   csl: &'csl mut Csl<DS, IS, OS, D>,
@@ -31,6 +40,67 @@ where
     Ok(Self { csl, curr_dim_idx, last_off })
   }
 
+  /// Verifies that the constructed dimensions, indices and offsets describe a valid instance,
+  /// i.e., all lines of the current dimensions were constructed.
+  ///
+  /// Violations related to a single element are returned as [`crate::Error::CslAt`] along
+  /// with the location of the offending element.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::{CslError, CslVec};
+  /// let mut csl = CslVec::<i32, 2>::default();
+  /// let constructor = csl.constructor()?.next_outermost_dim(3)?.next_outermost_dim(2)?;
+  /// let rslt = constructor.push_line([(0, 1)].into_iter())?.finish();
+  /// assert_eq!(rslt, Err(ndstruct::Error::Csl(CslError::InvalidOffsetsLength)));
+  ///
+  /// let mut other = CslVec::<i32, 2>::default();
+  /// let constructor = other.constructor()?.next_outermost_dim(3)?.next_outermost_dim(1)?;
+  /// let err = constructor.push_line([(0, 1), (5, 2)].into_iter())?.finish().unwrap_err();
+  /// assert_eq!(err.location().map(|location| location.pos), Some(1));
+  /// assert_eq!(err.without_location(), ndstruct::Error::Csl(CslError::IndcsGreaterThanEqualDimLength));
+  /// # Ok(()) }
+  #[inline]
+  pub fn finish(self) -> crate::Result<()> {
+    self.csl.validate()
+  }
+
+  /// Pushes empty lines until the next pushed line is located at `line_idx`. Passing the
+  /// number of lines of the current dimensions fills all remaining lines.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslVec;
+  /// let mut csl = CslVec::<i32, 2>::default();
+  /// csl
+  ///   .constructor()?
+  ///   .next_outermost_dim(3)?
+  ///   .next_outermost_dim(4)?
+  ///   .jump_to_line(2)?
+  ///   .push_line([(1, 1)].into_iter())?
+  ///   .jump_to_line(4)?
+  ///   .finish()?;
+  /// assert_eq!(csl, CslVec::new([4, 3], vec![1], vec![1], vec![0, 0, 0, 1, 1])?);
+  /// # Ok(()) }
+  #[inline]
+  pub fn jump_to_line(mut self, line_idx: usize) -> crate::Result<Self> {
+    let lines = self.lines();
+    if line_idx < lines {
+      return Err(CslLineConstructorError::LineAlreadyConstructed.into());
+    }
+    if line_idx > self.max_lines()? {
+      return Err(CslLineConstructorError::MaxNumOfLines.into());
+    }
+    for _ in lines..line_idx {
+      self = self.push_empty_line()?;
+    }
+    Ok(self)
+  }
+
   /// Jumps to the next outermost dimension, i.e., from right to left.
   ///
   /// # Example
@@ -60,6 +130,66 @@ where
     Ok(self)
   }
 
+  /// Pushes all lines created by a parallel iterator, preserving their order.
+  ///
+  /// Lines are collected and have their indices verified in parallel using `rayon`, then they
+  /// are pushed by [`push_line`](#method.push_line). Unsorted lines are reported before
+  /// anything is pushed.
+  ///
+  /// Storages only implement [`Push`], which means that their capacity can't be reserved and
+  /// lines can't be written in parallel. As such, every line is first buffered in its own
+  /// vector and the peak memory usage is about twice the size of all pushed elements.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::{CslLineConstructorError, CslVec};
+  /// use rayon::prelude::*;
+  /// let mut csl = CslVec::<usize, 2>::default();
+  /// csl
+  ///   .constructor()?
+  ///   .next_outermost_dim(4)?
+  ///   .next_outermost_dim(4)?
+  ///   .par_push_lines((0..4).into_par_iter().map(|row| [(row, row)]))?
+  ///   .finish()?;
+  /// assert_eq!(csl.indcs(), &[0, 1, 2, 3]);
+  ///
+  /// let mut other = CslVec::<usize, 2>::default();
+  /// let constructor = other.constructor()?.next_outermost_dim(2)?.next_outermost_dim(4)?;
+  /// let rslt = constructor.par_push_lines(vec![vec![(0, 0)], vec![(3, 3), (1, 1)]]);
+  /// assert_eq!(rslt.err(), Some(CslLineConstructorError::UnsortedIndices.into()));
+  /// assert_eq!(other.indcs(), &[]);
+  /// # Ok(()) }
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[inline]
+  pub fn par_push_lines<L>(
+    mut self,
+    lines: impl rayon::iter::IntoParallelIterator<Item = L>,
+  ) -> crate::Result<Self>
+  where
+    DATA: Send,
+    L: IntoIterator<Item = (usize, DATA)> + Send,
+  {
+    use rayon::prelude::*;
+    let last_dim = self.last_dim();
+    let collected = lines
+      .into_par_iter()
+      .map(|line| {
+        let elems: Vec<_> = line.into_iter().collect();
+        let pushed = elems.get(..last_dim).unwrap_or(&elems);
+        if crate::utils::windows2(pushed).any(|[a, b]| a.0 >= b.0) {
+          return Err(CslLineConstructorError::UnsortedIndices.into());
+        }
+        Ok(elems)
+      })
+      .collect::<crate::Result<Vec<_>>>()?;
+    for line in collected {
+      self = self.push_line(line.into_iter())?;
+    }
+    Ok(self)
+  }
+
   /// This is the same as `push_line([].iter(), [].iter())`.
   ///
   /// # Example
@@ -74,6 +204,7 @@ where
   /// # Ok(()) }
   #[inline]
   pub fn push_empty_line(self) -> crate::Result<Self> {
+    self.check_max_lines()?;
     self.csl.offs.push(self.last_off).map_err(|_err| crate::Error::InsufficientCapacity)?;
     Ok(self)
   }
@@ -101,6 +232,7 @@ where
   /// # Ok(()) }
   #[inline]
   pub fn push_line(mut self, di: impl Iterator<Item = (usize, DATA)>) -> crate::Result<Self> {
+    self.check_max_lines()?;
     let nnz_iter = 1..self.last_dim().saturating_add(1);
     let off_iter = self.last_off.saturating_add(1)..;
     let mut iter = off_iter.zip(nnz_iter.zip(di));
@@ -138,6 +270,35 @@ where
     Ok(self)
   }
 
+  /// Version of [`push_line`](#method.push_line) that accepts indices in any order.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::{CslRef, CslVec};
+  /// let mut csl = CslVec::<i32, 1>::default();
+  /// csl.constructor()?.next_outermost_dim(50)?.push_line_unsorted([(40, 2), (1, 1)].into_iter())?;
+  /// assert_eq!(csl.line([0]), CslRef::new([50], &[1, 2][..], &[1, 40][..], &[0, 2][..]).ok());
+  /// # Ok(()) }
+  #[cfg(feature = "alloc")]
+  #[inline]
+  pub fn push_line_unsorted(self, di: impl Iterator<Item = (usize, DATA)>) -> crate::Result<Self> {
+    let mut line: Vec<_> = di.collect();
+    line.sort_by_key(|(idx, _)| *idx);
+    if crate::utils::windows2(&line).any(|[a, b]| a.0 == b.0) {
+      return Err(CslLineConstructorError::DuplicatedIndices.into());
+    }
+    self.push_line(line.into_iter())
+  }
+
+  fn check_max_lines(&self) -> crate::Result<()> {
+    if self.lines() >= self.max_lines()? {
+      return Err(CslLineConstructorError::MaxNumOfLines.into());
+    }
+    Ok(())
+  }
+
   #[expect(clippy::unwrap_used, reason = "`self.curr_dim_idx` always points to a valid reference")]
   fn curr_dim(&mut self) -> &mut usize {
     self.csl.dims.get_mut(self.curr_dim_idx).unwrap()
@@ -147,6 +308,14 @@ where
   fn last_dim(&mut self) -> usize {
     *self.csl.dims.last().unwrap()
   }
+
+  fn lines(&self) -> usize {
+    self.csl.offs.as_ref().len().saturating_sub(1)
+  }
+
+  fn max_lines(&self) -> crate::Result<usize> {
+    Ok(correct_offs_len(&self.csl.dims)?.saturating_sub(1))
+  }
 }
 
 /// Contains all errors related to [`CslLineConstructor`].
@@ -161,6 +330,10 @@ pub enum CslLineConstructorError {
   EmptyDimension,
   /// The maximum number of lines for the currention dimension has been reached
   MaxNumOfLines,
+  /// Indices of the same line must be unique
+  DuplicatedIndices,
+  /// Lines can only be constructed from the first to the last
+  LineAlreadyConstructed,
 }
 
 impl Display for CslLineConstructorError {
@@ -1,4 +1,8 @@
+#[cfg(feature = "alloc")]
+use crate::csl::CslVec;
 use crate::csl::{csl_utils::outermost_offs, CslError, CslMut, CslRef};
+#[cfg(feature = "alloc")]
+use alloc::vec::{IntoIter, Vec};
 use core::mem;
 
 macro_rules! impl_iter {
@@ -30,6 +34,24 @@ macro_rules! impl_iter {
         }
       }
 
+      pub(crate) fn with_axis(
+        axis: usize,
+        mut dims: [usize; D],
+        data: $data_type,
+        indcs: &'slices [usize],
+        offs: &'slices [usize],
+      ) -> crate::Result<Self> {
+        if axis.saturating_add(1) >= D {
+          return Err(CslError::InvalidAxis.into());
+        }
+        let mut max_idx: usize = 1;
+        for dim in dims.iter_mut().take(axis.saturating_add(1)) {
+          max_idx = max_idx.saturating_mul(*dim);
+          *dim = 1;
+        }
+        Ok($csl_iter { curr_idx: 0, data, dims, indcs, max_idx, offs })
+      }
+
       #[cfg(feature = "rayon")]
       pub(crate) fn split_at(self, idx: usize) -> Option<[Self; 2]> {
         let cut_point = self.curr_idx.checked_add(idx)?;
@@ -113,7 +135,8 @@ macro_rules! impl_iter {
 
       #[inline]
       fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.max_idx, Some(self.max_idx))
+        let len = self.max_idx.saturating_sub(self.curr_idx);
+        (len, Some(len))
       }
     }
   };
@@ -121,3 +144,91 @@ macro_rules! impl_iter {
 
 impl_iter!(CslLineIterMut, &'slices mut [T], split_at_mut, CslMut);
 impl_iter!(CslLineIterRef, &'slices [T], split_at, CslRef);
+
+/// Iterator that returns owned lines of the outermost dimension of a [`CslVec`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct CslLineIntoIter<DATA, const D: usize> {
+  back_idx: usize,
+  data: IntoIter<DATA>,
+  dims: [usize; D],
+  front_idx: usize,
+  indcs: IntoIter<usize>,
+  offs: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<DATA, const D: usize> CslLineIntoIter<DATA, D> {
+  pub(crate) fn new(
+    mut dims: [usize; D],
+    data: Vec<DATA>,
+    indcs: Vec<usize>,
+    offs: Vec<usize>,
+  ) -> crate::Result<Self> {
+    if D < 2 {
+      return Err(CslError::InvalidIterDim.into());
+    }
+    let first = dims.first_mut().ok_or(CslError::InvalidIterDim)?;
+    let back_idx = *first;
+    *first = 1;
+    Ok(Self {
+      back_idx,
+      data: data.into_iter(),
+      dims,
+      front_idx: 0,
+      indcs: indcs.into_iter(),
+      offs,
+    })
+  }
+
+  // Number of elements and offsets, starting at zero, of the line located at `idx`.
+  fn line_offs(&self, idx: usize) -> Option<(usize, Vec<usize>)> {
+    let [offs_range, values] = outermost_offs(&self.dims, &self.offs, idx..idx.checked_add(1)?);
+    let nnz = values.end.checked_sub(values.start)?;
+    let offs = self.offs.get(offs_range)?.iter().map(|off| off.saturating_sub(values.start));
+    Some((nnz, offs.collect()))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<DATA, const D: usize> DoubleEndedIterator for CslLineIntoIter<DATA, D> {
+  #[inline]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.front_idx >= self.back_idx {
+      return None;
+    }
+    self.back_idx = self.back_idx.checked_sub(1)?;
+    let (nnz, offs) = self.line_offs(self.back_idx)?;
+    let mut data: Vec<_> = self.data.by_ref().rev().take(nnz).collect();
+    let mut indcs: Vec<_> = self.indcs.by_ref().rev().take(nnz).collect();
+    data.reverse();
+    indcs.reverse();
+    Some(CslVec { data, dims: self.dims.into(), indcs, offs })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<DATA, const D: usize> ExactSizeIterator for CslLineIntoIter<DATA, D> {}
+
+#[cfg(feature = "alloc")]
+impl<DATA, const D: usize> Iterator for CslLineIntoIter<DATA, D> {
+  type Item = CslVec<DATA, D>;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.front_idx >= self.back_idx {
+      return None;
+    }
+    let (nnz, offs) = self.line_offs(self.front_idx)?;
+    self.front_idx = self.front_idx.checked_add(1)?;
+    let data = self.data.by_ref().take(nnz).collect();
+    let indcs = self.indcs.by_ref().take(nnz).collect();
+    Some(CslVec { data, dims: self.dims.into(), indcs, offs })
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.back_idx.saturating_sub(self.front_idx);
+    (len, Some(len))
+  }
+}
@@ -0,0 +1,118 @@
+use crate::csl::csl_utils::line_coords;
+use core::mem;
+
+macro_rules! impl_iter {
+  ($csl_iter:ident, $data_type:ty, $item_type:ty, $split_at:ident, $split_first:ident, $split_last:ident) => {
+    /// Iterator of all non-zero elements of a CSL in storage order, alongside their respective
+    /// indices.
+    #[derive(Debug, Eq, PartialEq)]
+    pub struct $csl_iter<'slices, T, const D: usize> {
+      data: $data_type,
+      dims: [usize; D],
+      indcs: &'slices [usize],
+      line_coords: [usize; D],
+      line_idx: usize,
+      offs: &'slices [usize],
+      pos: usize,
+    }
+
+    impl<'slices, T, const D: usize> $csl_iter<'slices, T, D> {
+      pub(crate) fn new(
+        dims: [usize; D],
+        data: $data_type,
+        indcs: &'slices [usize],
+        offs: &'slices [usize],
+      ) -> Self {
+        let line_coords = line_coords(&dims, 0);
+        let pos = offs.first().copied().unwrap_or(0);
+        Self { data, dims, indcs, line_coords, line_idx: 0, offs, pos }
+      }
+
+      #[cfg(feature = "rayon")]
+      pub(crate) fn split_at(self, idx: usize) -> Option<[Self; 2]> {
+        if idx > self.data.len() || idx > self.indcs.len() {
+          return None;
+        }
+        let tail_pos = self.pos.checked_add(idx)?;
+        let tail_line_idx = self.offs.partition_point(|off| *off <= tail_pos).saturating_sub(1);
+        let (data_head, data_tail) = self.data.$split_at(idx);
+        let (indcs_head, indcs_tail) = self.indcs.split_at(idx);
+        Some([
+          $csl_iter {
+            data: data_head,
+            dims: self.dims,
+            indcs: indcs_head,
+            line_coords: self.line_coords,
+            line_idx: self.line_idx,
+            offs: self.offs,
+            pos: self.pos,
+          },
+          $csl_iter {
+            data: data_tail,
+            dims: self.dims,
+            indcs: indcs_tail,
+            line_coords: line_coords(&self.dims, tail_line_idx),
+            line_idx: tail_line_idx,
+            offs: self.offs,
+            pos: tail_pos,
+          },
+        ])
+      }
+    }
+
+    impl<T, const D: usize> DoubleEndedIterator for $csl_iter<'_, T, D> {
+      #[inline]
+      fn next_back(&mut self) -> Option<Self::Item> {
+        let (elem, data_head) = mem::take(&mut self.data).$split_last()?;
+        let (innermost_idx, indcs_head) = self.indcs.split_last()?;
+        self.data = data_head;
+        self.indcs = indcs_head;
+        let back_pos = self.pos.checked_add(self.indcs.len())?;
+        let line_idx = self.offs.partition_point(|off| *off <= back_pos).checked_sub(1)?;
+        let mut indcs = line_coords(&self.dims, line_idx);
+        *indcs.last_mut()? = *innermost_idx;
+        Some((indcs, elem))
+      }
+    }
+
+    impl<T, const D: usize> ExactSizeIterator for $csl_iter<'_, T, D> {}
+
+    impl<'slices, T, const D: usize> Iterator for $csl_iter<'slices, T, D> {
+      type Item = ([usize; D], $item_type);
+
+      #[inline]
+      fn next(&mut self) -> Option<Self::Item> {
+        let (elem, data_tail) = mem::take(&mut self.data).$split_first()?;
+        let (innermost_idx, indcs_tail) = self.indcs.split_first()?;
+        self.data = data_tail;
+        self.indcs = indcs_tail;
+        let prev_line_idx = self.line_idx;
+        while *self.offs.get(self.line_idx.checked_add(1)?)? <= self.pos {
+          self.line_idx = self.line_idx.checked_add(1)?;
+        }
+        if prev_line_idx != self.line_idx {
+          self.line_coords = line_coords(&self.dims, self.line_idx);
+        }
+        self.pos = self.pos.checked_add(1)?;
+        let mut indcs = self.line_coords;
+        *indcs.last_mut()? = *innermost_idx;
+        Some((indcs, elem))
+      }
+
+      #[inline]
+      fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.data.len(), Some(self.data.len()))
+      }
+    }
+  };
+}
+
+impl_iter!(
+  CslNnzIterMut,
+  &'slices mut [T],
+  &'slices mut T,
+  split_at_mut,
+  split_first_mut,
+  split_last_mut
+);
+impl_iter!(CslNnzIterRef, &'slices [T], &'slices T, split_at, split_first, split_last);
@@ -0,0 +1,353 @@
+use crate::csl::{csl_amd::approximate_minimum_degree, Csl, CslError, CslVec};
+use alloc::{collections::VecDeque, vec::Vec};
+use cl_aux::SingleTypeStorage;
+use core::cmp::Ordering;
+
+impl<DATA, DS, IS, OS> Csl<DS, IS, OS, 2>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Approximate Minimum Degree (AMD) fill-reducing ordering of the graph of `A + Aᵀ`.
+  ///
+  /// Eliminated vertices become elements of a quotient graph that absorb their adjacent
+  /// elements, so the fill of the elimination graph is never explicitly formed. Degrees are upper
+  /// bounds computed from the elements and vertices with identical adjacency are merged into
+  /// supervariables that are eliminated together. The returned vector maps new indices to old
+  /// indices.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslArray;
+  /// // Arrow matrix where the first row and column are filled
+  /// let csl = CslArray::new(
+  ///   [4, 4],
+  ///   [1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+  ///   [0, 1, 2, 3, 0, 1, 0, 2, 0, 3],
+  ///   [0, 4, 6, 8, 10],
+  /// )?;
+  /// assert_eq!(csl.approximate_minimum_degree()?, vec![1, 2, 0, 3]);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn approximate_minimum_degree(&self) -> crate::Result<Vec<usize>> {
+    approximate_minimum_degree(self.symmetric_adjacency()?).ok_or(crate::Error::UnknownError)
+  }
+
+  /// Fill-reducing ordering that recursively splits the graph of `A + Aᵀ` into two parts and a
+  /// separator using level structures, ordering the separator after both parts.
+  ///
+  /// Parts with at most `leaf_size` vertices or that can't be split any further are kept in
+  /// their natural order. The returned vector maps new indices to old indices.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslArray;
+  /// // Path graph: 0 - 1 - 2 - 3 - 4
+  /// let csl = CslArray::new(
+  ///   [5, 5],
+  ///   [1, 1, 1, 1, 1, 1, 1, 1],
+  ///   [1, 0, 2, 1, 3, 2, 4, 3],
+  ///   [0, 1, 3, 5, 7, 8],
+  /// )?;
+  /// assert_eq!(csl.nested_dissection(1)?, vec![3, 4, 0, 1, 2]);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn nested_dissection(&self, leaf_size: usize) -> crate::Result<Vec<usize>> {
+    let adjacency = self.symmetric_adjacency()?;
+    let mut buffer = LevelBuffer::new(adjacency.len());
+    let mut mark = alloc::vec![0; adjacency.len()];
+    let mut generation: usize = 0;
+    let mut rev_perm = Vec::with_capacity(adjacency.len());
+    let mut stack = alloc::vec![(0..adjacency.len()).collect::<Vec<_>>()];
+    while let Some(part) = stack.pop() {
+      generation = generation.wrapping_add(1);
+      if part.len() <= leaf_size.max(1) {
+        rev_perm.extend(part.iter().rev());
+        continue;
+      }
+      for vertex in part.iter().copied() {
+        if let Some(elem) = mark.get_mut(vertex) {
+          *elem = generation;
+        }
+      }
+      let Some(first_vertex) = part.first().copied() else {
+        continue;
+      };
+      let is_member = |vertex: usize| mark.get(vertex).is_some_and(|elem| *elem == generation);
+      let start = pseudo_peripheral_vertex(&adjacency, &mut buffer, first_vertex, is_member);
+      let levels = buffer.level_structure(&adjacency, start, is_member);
+      let max_level = levels.iter().map(|(_, level)| *level).max().unwrap_or(0);
+      if max_level < 2 && levels.len() == part.len() {
+        rev_perm.extend(part.iter().rev());
+        continue;
+      }
+      let separator_level = max_level / 2;
+      let [mut first, mut second, mut separator] = [Vec::new(), Vec::new(), Vec::new()];
+      for (vertex, level) in levels {
+        match level.cmp(&separator_level) {
+          Ordering::Less => first.push(vertex),
+          Ordering::Equal => separator.push(vertex),
+          Ordering::Greater => second.push(vertex),
+        }
+      }
+      second.extend(part.iter().copied().filter(|vertex| !buffer.is_visited(*vertex)));
+      if first.is_empty() && separator.is_empty() {
+        rev_perm.extend(part.iter().rev());
+        continue;
+      }
+      separator.sort_unstable();
+      rev_perm.extend(separator.iter().rev());
+      first.sort_unstable();
+      second.sort_unstable();
+      stack.push(first);
+      stack.push(second);
+    }
+    rev_perm.reverse();
+    Ok(rev_perm)
+  }
+
+  /// Applies row and column permutations, returning a new instance with sorted lines.
+  ///
+  /// Both permutations map new indices to old indices, i.e., the new element located at `[i, j]`
+  /// is the old element located at `[row_perm[i], col_perm[j]]`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::{CslArray, CslVec};
+  /// // [1, 2, _]
+  /// // [_, 3, _]
+  /// // [_, _, 4]
+  /// let csl = CslArray::new([3, 3], [1, 2, 3, 4], [0, 1, 1, 2], [0, 2, 3, 4])?;
+  /// let permuted = csl.permute(&[2, 0, 1], &[2, 0, 1])?;
+  /// assert_eq!(permuted, CslVec::new([3, 3], vec![4, 1, 2, 3], vec![0, 1, 2, 2], vec![0, 1, 3, 4])?);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn permute(&self, row_perm: &[usize], col_perm: &[usize]) -> crate::Result<CslVec<DATA, 2>>
+  where
+    DATA: Clone,
+  {
+    let [rows, cols] = self.dims.0;
+    let col_inv = inverse_perm(col_perm, cols)?;
+    let _row_inv = inverse_perm(row_perm, rows)?;
+    let data = self.data.as_ref();
+    let first_off = self.offs.as_ref().first().copied().unwrap_or(0);
+    let mut csl = CslVec::<DATA, 2> {
+      data: Vec::with_capacity(data.len()),
+      dims: self.dims,
+      indcs: Vec::with_capacity(data.len()),
+      offs: Vec::with_capacity(rows.saturating_add(1)),
+    };
+    csl.offs.push(0);
+    let mut line = Vec::new();
+    for old_row in row_perm.iter().copied() {
+      let opt_range = || {
+        let start = self.offs.as_ref().get(old_row)?.checked_sub(first_off)?;
+        let end = self.offs.as_ref().get(old_row.checked_add(1)?)?.checked_sub(first_off)?;
+        Some(start..end)
+      };
+      let range = opt_range().ok_or(crate::Error::UnknownError)?;
+      let indcs = self.indcs.as_ref().get(range.clone()).ok_or(crate::Error::UnknownError)?;
+      let values = data.get(range).ok_or(crate::Error::UnknownError)?;
+      line.clear();
+      for (old_col, value) in indcs.iter().zip(values) {
+        let new_col = col_inv.get(*old_col).copied().ok_or(crate::Error::UnknownError)?;
+        line.push((new_col, value));
+      }
+      line.sort_unstable_by_key(|(new_col, _)| *new_col);
+      for (new_col, value) in line.iter().copied() {
+        csl.indcs.push(new_col);
+        csl.data.push(value.clone());
+      }
+      csl.offs.push(csl.indcs.len());
+    }
+    Ok(csl)
+  }
+
+  /// Bandwidth-reducing ordering using the Reverse Cuthill–McKee algorithm over the graph of
+  /// `A + Aᵀ`. Each connected component starts at a pseudo-peripheral vertex.
+  ///
+  /// The returned vector maps new indices to old indices.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslArray;
+  /// // [1, _, _, 1]
+  /// // [_, 1, 1, _]
+  /// // [_, 1, 1, _]
+  /// // [1, _, _, 1]
+  /// let csl = CslArray::new([4, 4], [1, 1, 1, 1, 1, 1, 1, 1], [0, 3, 1, 2, 1, 2, 0, 3], [0, 2, 4, 6, 8])?;
+  /// let perm = csl.reverse_cuthill_mckee()?;
+  /// assert_eq!(perm, vec![1, 2, 0, 3]);
+  /// assert_eq!(csl.permute(&perm, &perm)?.bandwidth(), [1, 1]);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn reverse_cuthill_mckee(&self) -> crate::Result<Vec<usize>> {
+    let adjacency = self.symmetric_adjacency()?;
+    let mut buffer = LevelBuffer::new(adjacency.len());
+    let mut is_visited = alloc::vec![false; adjacency.len()];
+    let mut perm = Vec::with_capacity(adjacency.len());
+    let mut vertices: Vec<_> = (0..adjacency.len()).collect();
+    vertices.sort_by_key(|vertex| adjacency.get(*vertex).map_or(0, Vec::len));
+    for vertex in vertices {
+      if is_visited.get(vertex).copied().unwrap_or(true) {
+        continue;
+      }
+      let start = pseudo_peripheral_vertex(&adjacency, &mut buffer, vertex, |_| true);
+      let mut queue = VecDeque::from([start]);
+      if let Some(elem) = is_visited.get_mut(start) {
+        *elem = true;
+      }
+      while let Some(curr) = queue.pop_front() {
+        perm.push(curr);
+        let mut neighbors: Vec<_> = adjacency
+          .get(curr)
+          .into_iter()
+          .flatten()
+          .copied()
+          .filter(|neighbor| !is_visited.get(*neighbor).copied().unwrap_or(true))
+          .collect();
+        neighbors.sort_by_key(|neighbor| adjacency.get(*neighbor).map_or(0, Vec::len));
+        for neighbor in neighbors {
+          if let Some(elem) = is_visited.get_mut(neighbor) {
+            *elem = true;
+          }
+          queue.push_back(neighbor);
+        }
+      }
+    }
+    perm.reverse();
+    Ok(perm)
+  }
+
+  // Sorted adjacency lists of the graph of `A + Aᵀ` without self-loops.
+  fn symmetric_adjacency(&self) -> crate::Result<Vec<Vec<usize>>> {
+    let [rows, cols] = self.dims.0;
+    if rows != cols {
+      return Err(CslError::NonSquareMatrix.into());
+    }
+    let mut adjacency = alloc::vec![Vec::new(); rows];
+    for ([row, col], _) in self.iter_nnz() {
+      if row == col {
+        continue;
+      }
+      adjacency.get_mut(row).ok_or(crate::Error::UnknownError)?.push(col);
+      adjacency.get_mut(col).ok_or(crate::Error::UnknownError)?.push(row);
+    }
+    for adj in &mut adjacency {
+      adj.sort_unstable();
+      adj.dedup();
+    }
+    Ok(adjacency)
+  }
+}
+
+// Breadth-first state shared by successive level structures. Visited vertices are stamped with
+// the current generation, as such, the marks of a previous search are discarded in constant time.
+struct LevelBuffer {
+  generation: usize,
+  queue: VecDeque<(usize, usize)>,
+  stamps: Vec<usize>,
+}
+
+impl LevelBuffer {
+  fn new(vertices: usize) -> Self {
+    Self { generation: 0, queue: VecDeque::new(), stamps: alloc::vec![0; vertices] }
+  }
+
+  // If `vertex` was reached by the last level structure.
+  fn is_visited(&self, vertex: usize) -> bool {
+    self.stamps.get(vertex).map_or(true, |elem| *elem == self.generation)
+  }
+
+  // Breadth-first level of each vertex reachable from `start` among the vertices accepted by
+  // `is_member`. Vertices are ordered by level.
+  fn level_structure(
+    &mut self,
+    adjacency: &[Vec<usize>],
+    start: usize,
+    is_member: impl Fn(usize) -> bool,
+  ) -> Vec<(usize, usize)> {
+    self.generation = self.generation.wrapping_add(1);
+    if self.generation == 0 {
+      self.stamps.iter_mut().for_each(|elem| *elem = 0);
+      self.generation = 1;
+    }
+    let mut rslt = Vec::new();
+    self.queue.clear();
+    self.stamp(start);
+    self.queue.push_back((start, 0));
+    while let Some((curr, level)) = self.queue.pop_front() {
+      rslt.push((curr, level));
+      for neighbor in adjacency.get(curr).into_iter().flatten().copied() {
+        if is_member(neighbor) && !self.is_visited(neighbor) {
+          self.stamp(neighbor);
+          self.queue.push_back((neighbor, level.saturating_add(1)));
+        }
+      }
+    }
+    rslt
+  }
+
+  fn stamp(&mut self, vertex: usize) {
+    if let Some(elem) = self.stamps.get_mut(vertex) {
+      *elem = self.generation;
+    }
+  }
+}
+
+fn inverse_perm(perm: &[usize], len: usize) -> crate::Result<Vec<usize>> {
+  if perm.len() != len {
+    return Err(CslError::InvalidPermutation.into());
+  }
+  let mut inv = alloc::vec![usize::MAX; len];
+  for (new_idx, old_idx) in perm.iter().copied().enumerate() {
+    match inv.get_mut(old_idx) {
+      Some(elem) if *elem == usize::MAX => *elem = new_idx,
+      _ => return Err(CslError::InvalidPermutation.into()),
+    }
+  }
+  Ok(inv)
+}
+
+// George-Liu heuristic: repeatedly jumps to a vertex of minimum degree located at the last level
+// until the eccentricity stops growing.
+fn pseudo_peripheral_vertex(
+  adjacency: &[Vec<usize>],
+  buffer: &mut LevelBuffer,
+  start: usize,
+  is_member: impl Fn(usize) -> bool,
+) -> usize {
+  let degree = |vertex: usize| {
+    adjacency.get(vertex).map_or(0, |adj| adj.iter().filter(|elem| is_member(**elem)).count())
+  };
+  let mut curr = start;
+  let mut eccentricity = 0;
+  loop {
+    let levels = buffer.level_structure(adjacency, curr, &is_member);
+    let max_level = levels.iter().map(|(_, level)| *level).max().unwrap_or(0);
+    let candidate = levels
+      .iter()
+      .filter(|(_, level)| *level == max_level)
+      .map(|(vertex, _)| *vertex)
+      .min_by_key(|vertex| degree(*vertex))
+      .unwrap_or(curr);
+    if max_level <= eccentricity {
+      return curr;
+    }
+    eccentricity = max_level;
+    curr = candidate;
+  }
+}
@@ -1,5 +1,8 @@
 use crate::{
-  csl::{CslLineIterMut, CslLineIterRef, CslMut, CslRef},
+  csl::{
+    CslChunkIterMut, CslChunkIterRef, CslLineIterMut, CslLineIterRef, CslMut, CslNnzIterMut,
+    CslNnzIterRef, CslRef,
+  },
   ParallelIteratorWrapper, ParallelProducerWrapper,
 };
 use rayon::iter::{
@@ -8,13 +11,13 @@ use rayon::iter::{
 };
 
 macro_rules! create_rayon_iter {
-  ($csl_rayon_iter:ident, $ref:ident) => {
+  ($csl_rayon_iter:ident, $item:ty) => {
     impl<'slices, T, const D: usize> ParallelIterator
       for ParallelIteratorWrapper<$csl_rayon_iter<'slices, T, D>>
     where
       T: Send + Sync + 'slices,
     {
-      type Item = $ref<'slices, T, D>;
+      type Item = $item;
 
       #[inline]
       fn drive_unindexed<C>(self, consumer: C) -> C::Result
@@ -93,5 +96,9 @@ macro_rules! create_rayon_iter {
   };
 }
 
-create_rayon_iter!(CslLineIterRef, CslRef);
-create_rayon_iter!(CslLineIterMut, CslMut);
+create_rayon_iter!(CslChunkIterRef, CslRef<'slices, T, D>);
+create_rayon_iter!(CslChunkIterMut, CslMut<'slices, T, D>);
+create_rayon_iter!(CslLineIterRef, CslRef<'slices, T, D>);
+create_rayon_iter!(CslLineIterMut, CslMut<'slices, T, D>);
+create_rayon_iter!(CslNnzIterRef, ([usize; D], &'slices T));
+create_rayon_iter!(CslNnzIterMut, ([usize; D], &'slices mut T));
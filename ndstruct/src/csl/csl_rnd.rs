@@ -1,18 +1,260 @@
 use crate::csl::{
-  csl_utils::{correct_offs_len, manage_last_offset, outermost_stride},
+  csl_utils::{correct_offs_len, line_coords, manage_last_offset},
   Csl, CslError,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use cl_aux::{Push, SingleTypeStorage};
-use core::cmp::Ordering;
+use core::ops::Range;
 use rand::{
-  distributions::{Distribution, Uniform},
+  distributions::{Bernoulli, Distribution},
   Rng,
 };
 
+// Lines whose number of elements multiplied by this factor is greater or equal than the number of
+// allowed indices are filled using selection sampling instead of rejection sampling.
+const DENSE_LINE_FACTOR: usize = 4;
+const MAX_REJECTION_ROUNDS: usize = 8;
+
+/// Configurable generator of random and valid CSL instances.
+///
+/// The number of elements of each line is controlled by one of [`erdos_renyi`](#method.erdos_renyi),
+/// [`fixed_line_nnz`](#method.fixed_line_nnz), [`nnz`](#method.nnz) or
+/// [`power_law`](#method.power_law) while the innermost indices that each line can have are
+/// controlled by [`banded`](#method.banded) or [`block_diagonal`](#method.block_diagonal). The
+/// "row" of a line is its index in the second innermost dimension.
+///
+/// # Example
+#[cfg_attr(feature = "alloc", doc = "```rust")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use ndstruct::csl::{CslRndBuilder, CslVec};
+/// use rand::{rngs::mock::StepRng, Rng};
+/// let mut rng = StepRng::new(0, 1);
+/// let csl: CslVec<u8, 2> = CslRndBuilder::new([4, 4]).banded(1, 1).fixed_line_nnz(2).build(&mut rng, |r, _| r.gen()).unwrap();
+/// assert_eq!(csl.nnz(), 8);
+/// assert_eq!(csl.bandwidth(), [1, 1]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CslRndBuilder<const D: usize> {
+  dims: [usize; D],
+  line_nnz: LineNnz,
+  pattern: Pattern,
+  #[cfg(feature = "alloc")]
+  symmetric: bool,
+}
+
+impl<const D: usize> CslRndBuilder<D> {
+  /// Creates a builder for `dims` that, without further configuration, generates instances
+  /// without elements.
+  #[inline]
+  pub fn new(dims: [usize; D]) -> Self {
+    Self {
+      dims,
+      line_nnz: LineNnz::Uniform(0),
+      pattern: Pattern::Any,
+      #[cfg(feature = "alloc")]
+      symmetric: false,
+    }
+  }
+
+  /// Each line can only have indices within `[row - lower, row + upper]`.
+  #[inline]
+  #[must_use]
+  pub fn banded(mut self, lower: usize, upper: usize) -> Self {
+    self.pattern = Pattern::Banded { lower, upper };
+    self
+  }
+
+  /// Each line can only have indices that belong to the same square block of its row. Blocks
+  /// have `block` rows and columns, except possibly the last one.
+  #[inline]
+  #[must_use]
+  pub fn block_diagonal(mut self, block: usize) -> Self {
+    self.pattern = Pattern::BlockDiagonal(block.max(1));
+    self
+  }
+
+  /// Creates a new instance using `cb` to generate the data of each location.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::csl::{CslError, CslRndBuilder, CslVec};
+  /// use rand::rngs::mock::StepRng;
+  /// let mut rng = StepRng::new(0, 1);
+  /// let rslt: ndstruct::Result<CslVec<u8, 2>> = CslRndBuilder::new([2, 2]).nnz(5).build(&mut rng, |_, _| 1);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Csl(CslError::NnzGreaterThanMaximumNnz)));
+  /// ```
+  #[inline]
+  pub fn build<DATA, DS, IS, OS, R>(
+    self,
+    rng: &mut R,
+    cb: impl FnMut(&mut R, [usize; D]) -> DATA,
+  ) -> crate::Result<Csl<DS, IS, OS, D>>
+  where
+    DATA: Clone,
+    DS: AsMut<[DATA]> + AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+    IS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
+    OS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
+    R: Rng,
+  {
+    #[cfg(feature = "alloc")]
+    if self.symmetric {
+      return self.build_symmetric(rng, cb);
+    }
+    self.build_half(false, rng, cb)
+  }
+
+  /// Each location allowed by the current pattern is independently filled with `probability`,
+  /// i.e., the Erdős–Rényi `G(n, p)` model.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::csl::{CslRndBuilder, CslVec};
+  /// use rand::rngs::mock::StepRng;
+  /// let mut rng = StepRng::new(0, 1);
+  /// let csl: CslVec<u8, 2> = CslRndBuilder::new([3, 3]).erdos_renyi(1.0).build(&mut rng, |_, _| 1).unwrap();
+  /// assert_eq!(csl.nnz(), 9);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn erdos_renyi(mut self, probability: f64) -> Self {
+    self.line_nnz = LineNnz::ErdosRenyi(probability);
+    self
+  }
+
+  /// Every line has `line_nnz` elements or, if smaller, the number of allowed indices.
+  #[inline]
+  #[must_use]
+  pub fn fixed_line_nnz(mut self, line_nnz: usize) -> Self {
+    self.line_nnz = LineNnz::Fixed(line_nnz);
+    self
+  }
+
+  /// Randomly distributes `nnz` elements among all lines.
+  #[inline]
+  #[must_use]
+  pub fn nnz(mut self, nnz: usize) -> Self {
+    self.line_nnz = LineNnz::Uniform(nnz);
+    self
+  }
+
+  /// Distributes `nnz` elements among all lines following a power-law, i.e., the line with the
+  /// `r`-th largest number of elements receives a share proportional to `1 / (r + 1)^exponent`.
+  /// Lines are randomly ranked.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::csl::{CslRndBuilder, CslVec};
+  /// use rand::rngs::mock::StepRng;
+  /// let mut rng = StepRng::new(0, 1);
+  /// let csl: CslVec<u8, 2> = CslRndBuilder::new([4, 8]).power_law(12, 1).build(&mut rng, |_, _| 1).unwrap();
+  /// let mut line_nnz: Vec<_> = csl.line_nnz().collect();
+  /// line_nnz.sort_unstable();
+  /// assert_eq!(line_nnz, [1, 2, 3, 6]);
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn power_law(mut self, nnz: usize, exponent: u32) -> Self {
+    self.line_nnz = LineNnz::PowerLaw { exponent, nnz };
+    self
+  }
+
+  /// Generates a square symmetric matrix. Only 2-D structures are supported.
+  ///
+  /// The number of elements and the pattern are applied to the lower triangle, including the
+  /// main diagonal, before mirroring. Mirrored elements are clones of their counterparts.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslRndBuilder, CslVec};
+  /// use rand::{rngs::mock::StepRng, Rng};
+  /// let mut rng = StepRng::new(0, 1);
+  /// let csl: CslVec<u8, 2> = CslRndBuilder::new([5, 5]).nnz(7).symmetric().build(&mut rng, |r, _| r.gen()).unwrap();
+  /// assert!(csl.is_symmetric());
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[inline]
+  #[must_use]
+  pub fn symmetric(mut self) -> Self {
+    self.symmetric = true;
+    self
+  }
+
+  fn build_half<DATA, DS, IS, OS, R>(
+    self,
+    lower_triangle: bool,
+    rng: &mut R,
+    cb: impl FnMut(&mut R, [usize; D]) -> DATA,
+  ) -> crate::Result<Csl<DS, IS, OS, D>>
+  where
+    DS: AsMut<[DATA]> + AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+    IS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
+    OS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
+    R: Rng,
+  {
+    let mut csl = Csl { dims: self.dims.into(), ..Default::default() };
+    CslRnd::new(&mut csl, self.line_nnz, self.pattern, lower_triangle, rng)?
+      .fill(cb)
+      .ok_or(crate::Error::UnknownError)?;
+    Csl::new(csl.dims.0, csl.data, csl.indcs, csl.offs)
+  }
+
+  #[cfg(feature = "alloc")]
+  fn build_symmetric<DATA, DS, IS, OS, R>(
+    self,
+    rng: &mut R,
+    cb: impl FnMut(&mut R, [usize; D]) -> DATA,
+  ) -> crate::Result<Csl<DS, IS, OS, D>>
+  where
+    DATA: Clone,
+    DS: AsMut<[DATA]> + AsRef<[DATA]> + Default + Push<DATA> + SingleTypeStorage<Item = DATA>,
+    IS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
+    OS: AsMut<[usize]> + AsRef<[usize]> + Default + Push<usize>,
+    R: Rng,
+  {
+    let (2, Some(&rows), Some(&cols)) = (D, self.dims.first(), self.dims.last()) else {
+      return Err(CslError::NonSquareMatrix.into());
+    };
+    if rows != cols {
+      return Err(CslError::NonSquareMatrix.into());
+    }
+    let lower: Csl<DS, IS, OS, D> = self.build_half(true, rng, cb)?;
+    let mut elems = Vec::with_capacity(lower.nnz().saturating_mul(2));
+    for (indcs, value) in lower.iter_nnz() {
+      let [Some(row), Some(col)] = [indcs.first().copied(), indcs.last().copied()] else {
+        return Err(crate::Error::UnknownError);
+      };
+      elems.push((row, col, value));
+      if row != col {
+        elems.push((col, row, value));
+      }
+    }
+    elems.sort_unstable_by_key(|(row, col, _)| (*row, *col));
+    let mut csl = Csl::<DS, IS, OS, D> { dims: self.dims.into(), ..Default::default() };
+    let _ = manage_last_offset(&mut csl.offs)?;
+    let mut elems_iter = elems.into_iter().peekable();
+    for row in 0..rows {
+      while let Some((_, col, value)) = elems_iter.next_if(|(elem_row, _, _)| *elem_row == row) {
+        csl.data.push(value.clone()).map_err(|_err| crate::Error::InsufficientCapacity)?;
+        csl.indcs.push(col).map_err(|_err| crate::Error::InsufficientCapacity)?;
+      }
+      let off = csl.indcs.as_ref().len();
+      csl.offs.push(off).map_err(|_err| crate::Error::InsufficientCapacity)?;
+    }
+    Csl::new(csl.dims.0, csl.data, csl.indcs, csl.offs)
+  }
+}
+
 #[derive(Debug)]
 pub(crate) struct CslRnd<'csl, DS, IS, OS, R, const D: usize> {
   csl: &'csl mut Csl<DS, IS, OS, D>,
-  nnz: usize,
+  line_nnz: LineNnz,
+  lower_triangle: bool,
+  pattern: Pattern,
   rng: &'csl mut R,
 }
 
@@ -26,14 +268,27 @@ where
   #[inline]
   pub(crate) fn new(
     csl: &'csl mut Csl<DS, IS, OS, D>,
-    nnz: usize,
+    line_nnz: LineNnz,
+    pattern: Pattern,
+    lower_triangle: bool,
     rng: &'csl mut R,
   ) -> crate::Result<Self> {
-    if nnz > crate::utils::max_nnz(&csl.dims) {
-      return Err(CslError::NnzGreaterThanMaximumNnz.into());
+    let this = Self { csl, line_nnz, lower_triangle, pattern, rng };
+    match this.line_nnz {
+      LineNnz::ErdosRenyi(probability) => {
+        if !(0.0..=1.0).contains(&probability) {
+          return Err(CslError::InvalidProbability.into());
+        }
+      }
+      LineNnz::Fixed(_) => {}
+      LineNnz::PowerLaw { nnz, .. } | LineNnz::Uniform(nnz) => {
+        if nnz > this.max_nnz().ok_or(crate::Error::UnknownError)? {
+          return Err(CslError::NnzGreaterThanMaximumNnz.into());
+        }
+      }
     }
-    let _ = manage_last_offset(&mut csl.offs)?;
-    Ok(Self { csl, nnz, rng })
+    let _ = manage_last_offset(&mut this.csl.offs)?;
+    Ok(this)
   }
 
   #[inline]
@@ -43,12 +298,52 @@ where
     } else {
       self.csl.dims.len().checked_sub(1)?
     };
-    self.fill_offs(last_dim_idx)?;
-    self.fill_indcs(last_dim_idx)?;
+    match self.line_nnz {
+      LineNnz::ErdosRenyi(probability) => self.fill_erdos_renyi(probability)?,
+      LineNnz::Fixed(line_nnz) => {
+        self.push_line_counts(|_, _, _| Some(line_nnz))?;
+        self.accumulate_offs()?;
+        self.fill_indcs()?;
+      }
+      LineNnz::PowerLaw { exponent, nnz } => {
+        self.fill_power_law_counts(exponent, nnz)?;
+        self.fill_indcs()?;
+      }
+      LineNnz::Uniform(nnz) => {
+        self.fill_uniform_counts(nnz)?;
+        self.fill_indcs()?;
+      }
+    }
     self.fill_data(cb, last_dim_idx)?;
     Some(())
   }
 
+  // Range of innermost indices that the line located at `line_idx` can have.
+  fn allowed_indcs(&self, line_idx: usize) -> Option<Range<usize>> {
+    let dims = &self.csl.dims.0;
+    let innermost_dim = *dims.last()?;
+    let row = match D.checked_sub(2) {
+      Some(idx) => *line_coords(dims, line_idx).get(idx)?,
+      None => 0,
+    };
+    let mut range = match self.pattern {
+      Pattern::Any => 0..innermost_dim,
+      Pattern::Banded { lower, upper } => {
+        row.saturating_sub(lower)..row.saturating_add(upper).saturating_add(1)
+      }
+      Pattern::BlockDiagonal(block) => {
+        let start = row.checked_div(block)?.checked_mul(block)?;
+        start..start.saturating_add(block)
+      }
+    };
+    if self.lower_triangle {
+      range.end = range.end.min(row.saturating_add(1));
+    }
+    range.end = range.end.min(innermost_dim);
+    range.start = range.start.min(range.end);
+    Some(range)
+  }
+
   fn fill_data(
     &mut self,
     mut cb: impl FnMut(&mut R, [usize; D]) -> DATA,
@@ -56,105 +351,319 @@ where
   ) -> Option<()> {
     let data = &mut self.csl.data;
     let indcs = self.csl.indcs.as_ref();
-    let orig_dims = self.csl.dims.0;
-    let outermost_stride = outermost_stride(&orig_dims);
-
+    let dims = &self.csl.dims.0;
     for (line_idx, offset) in self.csl.offs.as_ref().windows(2).enumerate() {
-      let mut dims = orig_dims;
-      *dims.first_mut()? =
-        if outermost_stride == 0 { 0 } else { line_idx.checked_rem(outermost_stride)? };
-      let iter = dims.iter_mut().zip(orig_dims.iter()).skip(1).rev().skip(1);
-      for (dim, &orig_dim) in iter {
-        *dim = if orig_dim == 0 { 0 } else { line_idx.checked_rem(orig_dim)? };
-      }
+      let mut elem_indcs = line_coords(dims, line_idx);
       let range = *offset.first()?..*offset.get(1)?;
       for innermost_idx in indcs.get(range)?.iter().copied() {
-        *dims.get_mut(last_dim_idx)? = innermost_idx;
-        data.push(cb(self.rng, dims)).ok()?;
+        *elem_indcs.get_mut(last_dim_idx)? = innermost_idx;
+        data.push(cb(self.rng, elem_indcs)).ok()?;
       }
     }
 
     Some(())
   }
 
-  fn fill_indcs(&mut self, last_dim_idx: usize) -> Option<()> {
-    let dims = &self.csl.dims;
-    let indcs = &mut self.csl.indcs;
-    for offset in self.csl.offs.as_ref().windows(2) {
-      let mut counter = 0;
-      let line_nnz = offset.get(1)?.checked_sub(*offset.first()?)?;
-      while counter < line_nnz {
-        let rnd = self.rng.gen_range(0..*dims.get(last_dim_idx)?);
-        if !indcs.as_ref().get(*offset.first()?..)?.contains(&rnd) {
-          indcs.push(rnd).ok()?;
-          counter = counter.checked_add(1)?;
+  fn fill_erdos_renyi(&mut self, probability: f64) -> Option<()> {
+    let bernoulli = Bernoulli::new(probability).ok()?;
+    for line_idx in self.lines()? {
+      for idx in self.allowed_indcs(line_idx)? {
+        if bernoulli.sample(self.rng) {
+          self.csl.indcs.push(idx).ok()?;
         }
       }
-      indcs.as_mut().get_mut(*offset.first()?..)?.sort_unstable();
+      self.csl.offs.push(self.csl.indcs.as_ref().len()).ok()?;
     }
     Some(())
   }
 
-  fn fill_offs(&mut self, last_dim_idx: usize) -> Option<()> {
-    let nnz = self.nnz;
-    for _ in 1..correct_offs_len(&self.csl.dims).ok()? {
-      self.csl.offs.push(0).ok()?;
+  fn fill_indcs(&mut self) -> Option<()> {
+    for line_idx in self.lines()? {
+      let offs = self.csl.offs.as_ref();
+      let begin = *offs.get(line_idx)?;
+      let line_nnz = offs.get(line_idx.checked_add(1)?)?.checked_sub(begin)?;
+      let allowed_indcs = self.allowed_indcs(line_idx)?;
+      if line_nnz.checked_mul(DENSE_LINE_FACTOR)? >= allowed_indcs.len() {
+        self.push_selection_sample(allowed_indcs, line_nnz)?;
+      } else {
+        self.push_rejection_sample(allowed_indcs, begin, line_nnz)?;
+      }
     }
-    let fun = |idl, _, s: &mut Self| Some(Uniform::from(0..=idl).sample(s.rng));
-    let mut last_visited_off = self.do_fill_offs(last_dim_idx, fun)?;
-    loop {
-      if *self.csl.offs.as_ref().get(last_visited_off)? >= nnz {
-        if let Some(slice) = self.csl.offs.as_mut().get_mut(last_visited_off..) {
-          slice.iter_mut().for_each(|off| *off = nnz);
-        }
-        break;
+    Some(())
+  }
+
+  #[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss,
+    clippy::float_arithmetic,
+    reason = "Shares are approximations that are corrected by the remaining elements"
+  )]
+  fn fill_power_law_counts(&mut self, exponent: u32, nnz: usize) -> Option<()> {
+    let lines = self.lines()?.len();
+    let weight = |rank: usize| {
+      let base = rank.saturating_add(1) as f64;
+      1.0 / (0..exponent).fold(1.0, |acc, _| acc * base)
+    };
+    let total_weight: f64 = (0..lines).map(weight).sum();
+    let [multiplier, shift] = random_affine_permutation(self.rng, lines)?;
+    let rank_of = |line_idx: usize| -> Option<usize> {
+      let [a, b, c, d] = [multiplier, line_idx, shift, lines].map(|elem| u128::try_from(elem).ok());
+      let rslt = a?.checked_mul(b?)?.checked_add(c?)?.checked_rem(d?)?;
+      usize::try_from(rslt).ok()
+    };
+    let mut remaining = nnz;
+    self.push_line_counts(|line_idx, capacity, _| {
+      let share = (nnz as f64 * weight(rank_of(line_idx)?) / total_weight) as usize;
+      let line_nnz = share.min(capacity).min(remaining);
+      remaining = remaining.checked_sub(line_nnz)?;
+      Some(line_nnz)
+    })?;
+    let start = if lines == 0 { 0 } else { self.rng.gen_range(0..lines) };
+    self.distribute_remaining(remaining, start)?;
+    self.accumulate_offs()
+  }
+
+  // Every allowed position has the same probability, which means that each element is added to a
+  // line with a probability proportional to the remaining capacity of the line. Remaining
+  // capacities are stored as a Fenwick tree in the offsets.
+  fn fill_uniform_counts(&mut self, nnz: usize) -> Option<()> {
+    self.push_line_counts(|_, capacity, _| Some(capacity))?;
+    let tree = self.csl.offs.as_mut().get_mut(1..)?;
+    let mut total = tree.iter().try_fold(0usize, |acc, elem| acc.checked_add(*elem))?;
+    fenwick_build(tree)?;
+    for _ in 0..nnz {
+      if total == 0 {
+        return None;
       }
-      let mut offs_adjustment = 0;
-      last_visited_off = self.do_fill_offs(last_dim_idx, |idl, idx, s| {
-        let offs = s.csl.offs.as_mut();
-        let curr = offs.get(idx)?.checked_add(offs_adjustment)?;
-        let prev = *offs.get(idx.checked_sub(1)?)?;
-        let start = curr.checked_sub(prev)?;
-        let line_nnz = Uniform::from(start..=idl).sample(s.rng);
-        let offs_adjustment_addition = line_nnz.checked_add(prev)?.checked_sub(curr)?;
-        offs_adjustment = offs_adjustment.checked_add(offs_adjustment_addition)?;
-        Some(line_nnz)
-      })?;
+      let _ = fenwick_take(tree, self.rng.gen_range(0..total))?;
+      total = total.wrapping_sub(1);
+    }
+    fenwick_unbuild(tree)?;
+    for line_idx in self.lines()? {
+      let capacity = self.allowed_indcs(line_idx)?.len();
+      let line_nnz = self.csl.offs.as_mut().get_mut(line_idx.checked_add(1)?)?;
+      *line_nnz = capacity.checked_sub(*line_nnz)?;
+    }
+    self.accumulate_offs()
+  }
+
+  fn accumulate_offs(&mut self) -> Option<()> {
+    let mut last_off: usize = 0;
+    for off in self.csl.offs.as_mut() {
+      last_off = last_off.checked_add(*off)?;
+      *off = last_off;
+    }
+    Some(())
+  }
+
+  // Cyclically adds one element to each line with available space, starting at `line_idx`,
+  // until `remaining` elements are added. Offsets must still contain the number of elements of
+  // each line.
+  fn distribute_remaining(&mut self, mut remaining: usize, mut line_idx: usize) -> Option<()> {
+    let lines = self.lines()?.len();
+    let mut unchanged_lines: usize = 0;
+    while remaining > 0 {
+      if unchanged_lines > lines {
+        return None;
+      }
+      let capacity = self.allowed_indcs(line_idx)?.len();
+      let line_nnz = self.csl.offs.as_mut().get_mut(line_idx.checked_add(1)?)?;
+      if *line_nnz < capacity {
+        *line_nnz = line_nnz.checked_add(1)?;
+        remaining = remaining.checked_sub(1)?;
+        unchanged_lines = 0;
+      } else {
+        unchanged_lines = unchanged_lines.checked_add(1)?;
+      }
+      line_idx = line_idx.checked_add(1)?.checked_rem(lines)?;
+    }
+    Some(())
+  }
+
+  fn is_unconstrained(&self) -> bool {
+    self.pattern == Pattern::Any && !self.lower_triangle
+  }
+
+  fn max_nnz(&self) -> Option<usize> {
+    if self.is_unconstrained() {
+      return Some(crate::utils::max_nnz(&self.csl.dims));
+    }
+    self.lines()?.try_fold(0usize, |acc, line_idx| {
+      Some(acc.saturating_add(self.allowed_indcs(line_idx)?.len()))
+    })
+  }
+
+  fn lines(&self) -> Option<Range<usize>> {
+    Some(0..correct_offs_len(&self.csl.dims).ok()?.checked_sub(1)?)
+  }
+
+  // Pushes the number of elements of each line returned by `cb`, which receives the line index,
+  // the line capacity and `self`.
+  fn push_line_counts(
+    &mut self,
+    mut cb: impl FnMut(usize, usize, &mut Self) -> Option<usize>,
+  ) -> Option<()> {
+    for line_idx in self.lines()? {
+      let capacity = self.allowed_indcs(line_idx)?.len();
+      let line_nnz = cb(line_idx, capacity, self)?.min(capacity);
+      self.csl.offs.push(line_nnz).ok()?;
     }
     Some(())
   }
 
-  fn do_fill_offs(
+  // Rejection sampling that pushes random indices and then repeatedly sorts, removes duplicates
+  // and replaces them with new random indices. After `MAX_REJECTION_ROUNDS`, remaining duplicates
+  // are replaced by the smallest unused indices, which guarantees termination regardless of the
+  // generator.
+  fn push_rejection_sample(
     &mut self,
-    last_dim_idx: usize,
-    mut f: impl FnMut(usize, usize, &mut Self) -> Option<usize>,
-  ) -> Option<usize> {
-    let nnz = self.nnz;
-    let mut idx = 1;
-    let mut previous_nnz = *self.csl.offs.as_ref().first()?;
-    loop {
-      if idx >= self.csl.offs.as_ref().len() {
+    allowed_indcs: Range<usize>,
+    begin: usize,
+    line_nnz: usize,
+  ) -> Option<()> {
+    for _ in 0..line_nnz {
+      self.csl.indcs.push(self.rng.gen_range(allowed_indcs.clone())).ok()?;
+    }
+    let line = self.csl.indcs.as_mut().get_mut(begin..)?;
+    for round in 0.. {
+      line.sort_unstable();
+      let unique = move_unique_to_front(line)?;
+      if unique == line_nnz {
         break;
       }
-      match previous_nnz.cmp(&nnz) {
-        Ordering::Equal => {
-          break;
+      let (unique_indcs, duplicated_indcs) = line.split_at_mut(unique);
+      if round < MAX_REJECTION_ROUNDS {
+        for idx in duplicated_indcs {
+          *idx = self.rng.gen_range(allowed_indcs.clone());
         }
-        Ordering::Greater => {
-          let idx_minus_one = idx.checked_sub(1)?;
-          *self.csl.offs.as_mut().get_mut(idx_minus_one)? = nnz;
-          break;
-        }
-        Ordering::Less => {
-          let innermost_dim_len = *self.csl.dims.get(last_dim_idx)?;
-          let line_nnz = f(innermost_dim_len, idx, self)?;
-          let new_nnz = previous_nnz.checked_add(line_nnz)?;
-          *self.csl.offs.as_mut().get_mut(idx)? = new_nnz;
-          previous_nnz = new_nnz;
+      } else {
+        let mut unique_iter = unique_indcs.iter().peekable();
+        let mut unused = allowed_indcs.clone().filter(|idx| unique_iter.next_if_eq(&idx).is_none());
+        for idx in duplicated_indcs {
+          *idx = unused.next()?;
         }
       }
-      idx = idx.checked_add(1)?;
     }
-    idx.checked_sub(1)
+    Some(())
+  }
+
+  // Knuth's selection sampling, which directly generates sorted indices.
+  fn push_selection_sample(&mut self, allowed_indcs: Range<usize>, line_nnz: usize) -> Option<()> {
+    let mut needed = line_nnz;
+    let end = allowed_indcs.end;
+    for idx in allowed_indcs {
+      if needed == 0 {
+        break;
+      }
+      if self.rng.gen_range(0..end.checked_sub(idx)?) < needed {
+        self.csl.indcs.push(idx).ok()?;
+        needed = needed.checked_sub(1)?;
+      }
+    }
+    Some(())
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum LineNnz {
+  ErdosRenyi(f64),
+  Fixed(usize),
+  PowerLaw { exponent: u32, nnz: usize },
+  Uniform(usize),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Pattern {
+  Any,
+  Banded { lower: usize, upper: usize },
+  BlockDiagonal(usize),
+}
+
+// Converts `tree` into a Fenwick tree of its elements in linear time, where the node `n` (one-based)
+// is located at `tree[n - 1]`.
+fn fenwick_build(tree: &mut [usize]) -> Option<()> {
+  for node in 1..=tree.len() {
+    let parent = node.checked_add(node & node.wrapping_neg())?;
+    if let Some(parent_idx) = parent.checked_sub(1).filter(|idx| *idx < tree.len()) {
+      let value = *tree.get(node.wrapping_sub(1))?;
+      let elem = tree.get_mut(parent_idx)?;
+      *elem = elem.checked_add(value)?;
+    }
+  }
+  Some(())
+}
+
+// Finds and decrements the element that contains the `target`-th unit, i.e., the first element
+// whose prefix sum is greater than `target`, returning its index.
+fn fenwick_take(tree: &mut [usize], target: usize) -> Option<usize> {
+  let mut idx: usize = 0;
+  let mut rest = target;
+  let mut step: usize = 1;
+  while step <= tree.len().wrapping_shr(1) {
+    step = step.checked_mul(2)?;
+  }
+  while step > 0 {
+    let next = idx.checked_add(step)?;
+    if let Some(value) = tree.get(next.wrapping_sub(1)).copied().filter(|value| *value <= rest) {
+      rest = rest.wrapping_sub(value);
+      idx = next;
+    }
+    step = step.wrapping_shr(1);
+  }
+  let mut node = idx.checked_add(1)?;
+  while let Some(elem) = tree.get_mut(node.wrapping_sub(1)) {
+    *elem = elem.checked_sub(1)?;
+    node = node.checked_add(node & node.wrapping_neg())?;
+  }
+  Some(idx)
+}
+
+// Inverse of `fenwick_build`.
+fn fenwick_unbuild(tree: &mut [usize]) -> Option<()> {
+  for node in (1..=tree.len()).rev() {
+    let parent = node.checked_add(node & node.wrapping_neg())?;
+    if let Some(parent_idx) = parent.checked_sub(1).filter(|idx| *idx < tree.len()) {
+      let value = *tree.get(node.wrapping_sub(1))?;
+      let elem = tree.get_mut(parent_idx)?;
+      *elem = elem.checked_sub(value)?;
+    }
+  }
+  Some(())
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+  while b != 0 {
+    let rem = a.checked_rem(b).unwrap_or(0);
+    a = b;
+    b = rem;
+  }
+  a
+}
+
+// Moves the first occurrence of each element of the sorted `slice` to the front, returning the
+// number of unique elements.
+fn move_unique_to_front(slice: &mut [usize]) -> Option<usize> {
+  let mut unique: usize = 0;
+  for idx in 0..slice.len() {
+    let elem = *slice.get(idx)?;
+    if unique == 0 || slice.get(unique.checked_sub(1)?) != Some(&elem) {
+      *slice.get_mut(unique)? = elem;
+      unique = unique.checked_add(1)?;
+    }
+  }
+  Some(unique)
+}
+
+// Parameters of `(multiplier * idx + shift) % len`, which is a permutation of `0..len`.
+fn random_affine_permutation(rng: &mut impl Rng, len: usize) -> Option<[usize; 2]> {
+  if len <= 1 {
+    return Some([1, 0]);
+  }
+  let mut multiplier = rng.gen_range(1..len);
+  while gcd(multiplier, len) != 1 {
+    multiplier = multiplier.checked_rem(len.checked_sub(1)?)?.checked_add(1)?;
   }
+  Some([multiplier, rng.gen_range(0..len)])
 }
@@ -0,0 +1,322 @@
+use crate::{
+  csl::{csl_utils::data_idx, Csl},
+  utils::windows2,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+
+impl<DATA, DS, IS, OS, const D: usize> Csl<DS, IS, OS, D>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Number of lines without any stored element.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// assert_eq!(csl_array_4().empty_lines(), 17);
+  /// ```
+  #[inline]
+  pub fn empty_lines(&self) -> usize {
+    windows2(self.offs.as_ref()).filter(|[a, b]| a == b).count()
+  }
+
+  /// Number of stored elements of each line.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// assert!(csl_array_4().line_nnz().take(5).eq([2, 1, 0, 2, 1]));
+  /// ```
+  #[inline]
+  pub fn line_nnz(&self) -> impl Iterator<Item = usize> + '_ {
+    windows2(self.offs.as_ref()).map(|[a, b]| b.saturating_sub(*a))
+  }
+
+  /// Histogram of the number of stored elements per line, i.e., the element located at index `n`
+  /// is the number of lines that have exactly `n` stored elements.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// assert_eq!(csl_array_4().line_nnz_histogram(), vec![17, 5, 2]);
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[inline]
+  pub fn line_nnz_histogram(&self) -> Vec<usize> {
+    let mut histogram = Vec::new();
+    for line_nnz in self.line_nnz() {
+      add_to_histogram(&mut histogram, line_nnz, 1);
+    }
+    histogram
+  }
+
+  /// Parallel version of [`SparseTensor::axis_nnz`](crate::SparseTensor::axis_nnz) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::{doc_tests::csl_array_4, SparseTensor};
+  /// let csl = csl_array_4();
+  /// assert_eq!(csl.par_axis_nnz(1), csl.axis_nnz(1));
+  /// ```
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[inline]
+  pub fn par_axis_nnz(&self, axis: usize) -> Option<Vec<usize>>
+  where
+    DATA: Send + Sync,
+  {
+    crate::utils::par_axis_nnz(self.par_iter_nnz(), &self.dims, axis)
+  }
+
+  /// Parallel version of [`empty_lines`](#method.empty_lines) using `rayon`.
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_empty_lines(&self) -> usize {
+    use rayon::prelude::*;
+    self.offs.as_ref().par_windows(2).filter(|window| window.first() == window.get(1)).count()
+  }
+
+  /// Parallel version of [`line_nnz_histogram`](#method.line_nnz_histogram) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::doc_tests::csl_array_4;
+  /// let csl = csl_array_4();
+  /// assert_eq!(csl.par_line_nnz_histogram(), csl.line_nnz_histogram());
+  /// ```
+  #[cfg(all(feature = "alloc", feature = "rayon"))]
+  #[inline]
+  pub fn par_line_nnz_histogram(&self) -> Vec<usize> {
+    use rayon::prelude::*;
+    self
+      .offs
+      .as_ref()
+      .par_windows(2)
+      .fold(Vec::new, |mut histogram, window| {
+        let line_nnz = window.get(1).zip(window.first()).map_or(0, |(b, a)| b.saturating_sub(*a));
+        add_to_histogram(&mut histogram, line_nnz, 1);
+        histogram
+      })
+      .reduce(Vec::new, |mut a, b| {
+        for (line_nnz, lines) in b.into_iter().enumerate() {
+          add_to_histogram(&mut a, line_nnz, lines);
+        }
+        a
+      })
+  }
+}
+
+impl<DATA, DS, IS, OS> Csl<DS, IS, OS, 2>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// The lower and upper bandwidths, i.e., the greatest distances of a stored element below or
+  /// above the main diagonal.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::CslArray;
+  /// // [1, 2, _, _]
+  /// // [_, 3, _, 4]
+  /// // [5, _, 6, _]
+  /// let csl = CslArray::new([3, 4], [1, 2, 3, 4, 5, 6], [0, 1, 1, 3, 0, 2], [0, 2, 4, 6]).unwrap();
+  /// assert_eq!(csl.bandwidth(), [2, 2]);
+  /// ```
+  #[inline]
+  pub fn bandwidth(&self) -> [usize; 2] {
+    self.lines().fold([0, 0], |acc, (row, indcs)| bandwidth_of_line(acc, row, indcs))
+  }
+
+  /// If all stored elements have a stored mirrored counterpart with the same value.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::CslArray;
+  /// let csl = CslArray::new([2, 2], [1, 2, 2], [0, 1, 0], [0, 2, 3]).unwrap();
+  /// assert!(csl.is_symmetric());
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 1, 0], [0, 2, 3]).unwrap();
+  /// assert!(!csl.is_symmetric());
+  /// assert!(csl.is_structurally_symmetric());
+  /// ```
+  #[inline]
+  pub fn is_symmetric(&self) -> bool
+  where
+    DATA: PartialEq,
+  {
+    self.lines().all(|(row, indcs)| {
+      self.is_line_symmetric(row, indcs, |a, b| self.data().get(a) == self.data().get(b))
+    })
+  }
+
+  /// If all stored elements have a stored mirrored counterpart, regardless of their values.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::CslArray;
+  /// let csl = CslArray::new([2, 2], [1, 2], [0, 1], [0, 2, 2]).unwrap();
+  /// assert!(!csl.is_structurally_symmetric());
+  /// ```
+  #[inline]
+  pub fn is_structurally_symmetric(&self) -> bool {
+    self.lines().all(|(row, indcs)| self.is_line_symmetric(row, indcs, |_, _| true))
+  }
+
+  /// Parallel version of [`bandwidth`](#method.bandwidth) using `rayon`.
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_bandwidth(&self) -> [usize; 2]
+  where
+    DATA: Sync,
+    IS: Sync,
+    OS: Sync,
+  {
+    use rayon::prelude::*;
+    self.par_lines().fold(|| [0, 0], |acc, (row, indcs)| bandwidth_of_line(acc, row, indcs)).reduce(
+      || [0, 0],
+      |[a_lower, a_upper], [b_lower, b_upper]| [a_lower.max(b_lower), a_upper.max(b_upper)],
+    )
+  }
+
+  /// Parallel version of [`is_symmetric`](#method.is_symmetric) using `rayon`.
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_is_symmetric(&self) -> bool
+  where
+    DATA: PartialEq + Sync,
+    DS: Sync,
+    IS: Sync,
+    OS: Sync,
+  {
+    use rayon::prelude::*;
+    self.par_lines().all(|(row, indcs)| {
+      self.is_line_symmetric(row, indcs, |a, b| self.data().get(a) == self.data().get(b))
+    })
+  }
+
+  /// Parallel version of [`is_structurally_symmetric`](#method.is_structurally_symmetric) using
+  /// `rayon`.
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_is_structurally_symmetric(&self) -> bool
+  where
+    DS: Sync,
+    IS: Sync,
+    OS: Sync,
+  {
+    use rayon::prelude::*;
+    self.par_lines().all(|(row, indcs)| self.is_line_symmetric(row, indcs, |_, _| true))
+  }
+
+  /// Parallel version of [`profile`](#method.profile) using `rayon`.
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_profile(&self) -> usize
+  where
+    IS: Sync,
+    OS: Sync,
+  {
+    use rayon::prelude::*;
+    self
+      .par_lines()
+      .map(|(row, indcs)| profile_of_line(row, indcs))
+      .reduce(|| 0, usize::saturating_add)
+  }
+
+  /// The profile (or envelope size), i.e., the sum of the distances between the main diagonal and
+  /// the first stored element of each row located before the main diagonal.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::CslArray;
+  /// // [1, 2, _, _]
+  /// // [_, 3, _, 4]
+  /// // [5, _, 6, _]
+  /// let csl = CslArray::new([3, 4], [1, 2, 3, 4, 5, 6], [0, 1, 1, 3, 0, 2], [0, 2, 4, 6]).unwrap();
+  /// assert_eq!(csl.profile(), 2);
+  /// ```
+  #[inline]
+  pub fn profile(&self) -> usize {
+    self.lines().map(|(row, indcs)| profile_of_line(row, indcs)).fold(0, usize::saturating_add)
+  }
+
+  // `cmp` compares the data indices of an element and its mirrored counterpart.
+  fn is_line_symmetric(
+    &self,
+    row: usize,
+    indcs: &[usize],
+    cmp: impl Fn(usize, usize) -> bool,
+  ) -> bool {
+    let Some(first_off) = self.offs.as_ref().first() else {
+      return true;
+    };
+    let Some(start) = self.offs.as_ref().get(row).and_then(|off| off.checked_sub(*first_off))
+    else {
+      return false;
+    };
+    indcs.iter().enumerate().all(|(idx, col)| {
+      let Some(mirrored_idx) = data_idx(self, [*col, row]) else {
+        return false;
+      };
+      start.checked_add(idx).is_some_and(|data_idx| cmp(data_idx, mirrored_idx))
+    })
+  }
+
+  fn lines(&self) -> impl Iterator<Item = (usize, &[usize])> {
+    let first_off = self.offs.as_ref().first().copied().unwrap_or(0);
+    let indcs = self.indcs.as_ref();
+    windows2(self.offs.as_ref()).enumerate().map(move |(row, [a, b])| {
+      let range = a.saturating_sub(first_off)..b.saturating_sub(first_off);
+      (row, indcs.get(range).unwrap_or_default())
+    })
+  }
+
+  #[cfg(feature = "rayon")]
+  fn par_lines(&self) -> impl rayon::iter::IndexedParallelIterator<Item = (usize, &[usize])>
+  where
+    IS: Sync,
+    OS: Sync,
+  {
+    use rayon::prelude::*;
+    let first_off = self.offs.as_ref().first().copied().unwrap_or(0);
+    let indcs = self.indcs.as_ref();
+    self.offs.as_ref().par_windows(2).enumerate().map(move |(row, window)| {
+      let [a, b] = [window.first(), window.get(1)].map(|elem| elem.copied().unwrap_or(first_off));
+      let range = a.saturating_sub(first_off)..b.saturating_sub(first_off);
+      (row, indcs.get(range).unwrap_or_default())
+    })
+  }
+}
+
+#[cfg(feature = "alloc")]
+fn add_to_histogram(histogram: &mut Vec<usize>, idx: usize, value: usize) {
+  if histogram.len() <= idx {
+    histogram.resize(idx.saturating_add(1), 0);
+  }
+  if let Some(elem) = histogram.get_mut(idx) {
+    *elem = elem.saturating_add(value);
+  }
+}
+
+fn bandwidth_of_line([lower, upper]: [usize; 2], row: usize, indcs: &[usize]) -> [usize; 2] {
+  let first = indcs.first().map_or(0, |col| row.saturating_sub(*col));
+  let last = indcs.last().map_or(0, |col| col.saturating_sub(row));
+  [lower.max(first), upper.max(last)]
+}
+
+fn profile_of_line(row: usize, indcs: &[usize]) -> usize {
+  indcs.first().map_or(0, |col| row.saturating_sub(*col))
+}
@@ -0,0 +1,275 @@
+#[cfg(feature = "alloc")]
+use crate::csl::CslVec;
+use crate::{
+  csl::{Csl, CslError},
+  num::Num,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+
+/// Symmetric CSL backed by a static array.
+pub type SymCslArray<DATA, const N: usize, const O: usize> =
+  SymCsl<[DATA; N], [usize; N], [usize; O]>;
+/// Symmetric CSL backed by a mutable slice
+pub type SymCslMut<'data, DATA> = SymCsl<&'data mut [DATA], &'data [usize], &'data [usize]>;
+/// Symmetric CSL backed by a slice
+pub type SymCslRef<'data, DATA> = SymCsl<&'data [DATA], &'data [usize], &'data [usize]>;
+/// Symmetric CSL backed by a dynamic vector.
+#[cfg(feature = "alloc")]
+pub type SymCslVec<DATA> = SymCsl<Vec<DATA>, Vec<usize>, Vec<usize>>;
+
+/// The half of a square matrix, including the main diagonal, that is stored by [`SymCsl`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Triangle {
+  /// Elements located at `[row, col]` where `col <= row`.
+  Lower,
+  /// Elements located at `[row, col]` where `col >= row`.
+  Upper,
+}
+
+impl Triangle {
+  /// If the element located at `[row, col]` belongs to this triangle.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::Triangle;
+  /// assert!(Triangle::Lower.contains([1, 0]));
+  /// assert!(!Triangle::Upper.contains([1, 0]));
+  /// ```
+  #[inline]
+  pub fn contains(self, [row, col]: [usize; 2]) -> bool {
+    match self {
+      Self::Lower => col <= row,
+      Self::Upper => col >= row,
+    }
+  }
+}
+
+/// Square symmetric matrix that only stores the elements of one [`Triangle`] in a 2-D [`Csl`].
+///
+/// Every element of the other triangle is implicitly defined by its mirrored counterpart, which
+/// roughly halves the memory usage of symmetric matrices.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SymCsl<DS, IS, OS> {
+  csl: Csl<DS, IS, OS, 2>,
+  triangle: Triangle,
+}
+
+impl<DATA, DS, IS, OS> SymCsl<DS, IS, OS>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Creates a valid instance from a square `csl` whose elements all belong to `triangle`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// // [1, 2]
+  /// // [_, 3]
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 1, 1], [0, 2, 3]).unwrap();
+  /// let sym = SymCsl::new(csl, Triangle::Upper).unwrap();
+  /// assert_eq!(sym.value([1, 0]), Some(&2));
+  /// ```
+  #[inline]
+  pub fn new(csl: Csl<DS, IS, OS, 2>, triangle: Triangle) -> crate::Result<Self> {
+    let [rows, cols] = csl.dims.0;
+    if rows != cols {
+      return Err(CslError::NonSquareMatrix.into());
+    }
+    if csl.iter_nnz().any(|(indcs, _)| !triangle.contains(indcs)) {
+      return Err(CslError::ElementOutsideTriangle.into());
+    }
+    Ok(Self { csl, triangle })
+  }
+
+  /// The underlying half matrix.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// let sym = SymCsl::new(csl.clone(), Triangle::Lower).unwrap();
+  /// assert_eq!(sym.csl(), &csl);
+  /// ```
+  #[inline]
+  pub fn csl(&self) -> &Csl<DS, IS, OS, 2> {
+    &self.csl
+  }
+
+  /// Number of rows and columns.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// assert_eq!(SymCsl::new(csl, Triangle::Lower).unwrap().dim(), 2);
+  /// ```
+  #[inline]
+  pub fn dim(&self) -> usize {
+    self.csl.dims.0[0]
+  }
+
+  /// Consumes this instance and returns the underlying half matrix.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// let sym = SymCsl::new(csl.clone(), Triangle::Lower).unwrap();
+  /// assert_eq!(sym.into_csl(), csl);
+  /// ```
+  #[inline]
+  pub fn into_csl(self) -> Csl<DS, IS, OS, 2> {
+    self.csl
+  }
+
+  /// Number of logical non zero elements, i.e., mirrored elements are counted twice.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// assert_eq!(SymCsl::new(csl, Triangle::Lower).unwrap().nnz(), 4);
+  /// ```
+  #[inline]
+  pub fn nnz(&self) -> usize {
+    self
+      .csl
+      .iter_nnz()
+      .fold(0, |acc, ([row, col], _)| acc.saturating_add(if row == col { 1 } else { 2 }))
+  }
+
+  /// Sparse matrix-vector multiplication that writes `self * x` into `y`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// // [1, 2]
+  /// // [2, 3]
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// let sym = SymCsl::new(csl, Triangle::Lower).unwrap();
+  /// let mut y = [0; 2];
+  /// sym.spmv(&[1, 10], &mut y).unwrap();
+  /// assert_eq!(y, [21, 32]);
+  /// ```
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn spmv(&self, x: &[DATA], y: &mut [DATA]) -> crate::Result<()>
+  where
+    DATA: Num,
+  {
+    let dim = self.dim();
+    if x.len() != dim || y.len() != dim {
+      return Err(CslError::InvalidVectorLength.into());
+    }
+    y.iter_mut().for_each(|elem| *elem = DATA::zero());
+    for ([row, col], value) in self.csl.iter_nnz() {
+      let [Some(x_row), Some(x_col)] = [x.get(row).copied(), x.get(col).copied()] else {
+        return Err(crate::Error::UnknownError);
+      };
+      let y_row = y.get_mut(row).ok_or(crate::Error::UnknownError)?;
+      *y_row += *value * x_col;
+      if row != col {
+        let y_col = y.get_mut(col).ok_or(crate::Error::UnknownError)?;
+        *y_col += *value * x_row;
+      }
+    }
+    Ok(())
+  }
+
+  /// Expands both triangles into a full 2-D [`Csl`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, CslVec, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// let full = SymCsl::new(csl, Triangle::Lower).unwrap().to_csl().unwrap();
+  /// assert_eq!(full, CslVec::new([2, 2], vec![1, 2, 2, 3], vec![0, 1, 0, 1], vec![0, 2, 4]).unwrap());
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[inline]
+  pub fn to_csl(&self) -> crate::Result<CslVec<DATA, 2>>
+  where
+    DATA: Clone,
+  {
+    let mut elems: Vec<_> = self
+      .csl
+      .iter_nnz()
+      .flat_map(|([row, col], value)| {
+        let mirrored = (row != col).then_some(([col, row], value));
+        [Some(([row, col], value)), mirrored].into_iter().flatten()
+      })
+      .collect();
+    elems.sort_unstable_by_key(|(indcs, _)| *indcs);
+    let dim = self.dim();
+    let mut rslt = CslVec::<DATA, 2> {
+      data: Vec::with_capacity(elems.len()),
+      dims: [dim, dim].into(),
+      indcs: Vec::with_capacity(elems.len()),
+      offs: Vec::with_capacity(dim.saturating_add(1)),
+    };
+    rslt.offs.push(0);
+    let mut elems_iter = elems.into_iter().peekable();
+    for row in 0..dim {
+      while let Some(([_, col], value)) = elems_iter.next_if(|([elem_row, _], _)| *elem_row == row)
+      {
+        rslt.data.push(value.clone());
+        rslt.indcs.push(col);
+      }
+      rslt.offs.push(rslt.indcs.len());
+    }
+    Ok(rslt)
+  }
+
+  /// The stored triangle.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2, 3], [0, 0, 1], [0, 1, 3]).unwrap();
+  /// assert_eq!(SymCsl::new(csl, Triangle::Lower).unwrap().triangle(), Triangle::Lower);
+  /// ```
+  #[inline]
+  pub fn triangle(&self) -> Triangle {
+    self.triangle
+  }
+
+  /// Immutable reference of the element located at `[row, col]` or at its mirrored location
+  /// `[col, row]`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::csl::{CslArray, SymCsl, Triangle};
+  /// let csl = CslArray::new([2, 2], [1, 2], [0, 0], [0, 1, 2]).unwrap();
+  /// let sym = SymCsl::new(csl, Triangle::Lower).unwrap();
+  /// assert_eq!(sym.value([0, 1]), Some(&2));
+  /// assert_eq!(sym.value([1, 0]), Some(&2));
+  /// assert_eq!(sym.value([1, 1]), None);
+  /// ```
+  #[inline]
+  pub fn value(&self, [row, col]: [usize; 2]) -> Option<&DATA> {
+    if self.triangle.contains([row, col]) {
+      self.csl.value([row, col])
+    } else {
+      self.csl.value([col, row])
+    }
+  }
+}
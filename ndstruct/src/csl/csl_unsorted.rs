@@ -0,0 +1,171 @@
+use crate::{
+  csl::{CslError, CslVec},
+  utils::{are_in_ascending_order, windows2},
+};
+use alloc::vec::Vec;
+
+type Lines<'data, DATA> = Vec<(&'data mut [DATA], &'data mut [usize])>;
+
+impl<DATA, const D: usize> CslVec<DATA, D> {
+  /// Creates a valid instance from raw parts whose lines can have unsorted or duplicated
+  /// innermost indices.
+  ///
+  /// The indices of each line are sorted together with their data and every element that shares
+  /// the same line and innermost index of a previous element is merged into the latter by
+  /// `dup_policy`, which receives the previous element and the duplicated element in their
+  /// original order.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `data`: Data collection
+  /// * `indcs`: Indices of each data item
+  /// * `offs`: Offset of each innermost line
+  /// * `dup_policy`: Callback that merges duplicated elements
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslVec;
+  /// let csl = CslVec::from_raw_unsorted(
+  ///   [2, 3],
+  ///   vec![1, 2, 5, 3],
+  ///   vec![2, 1, 1, 0],
+  ///   vec![0, 3, 4],
+  ///   |prev, dup| *prev += dup,
+  /// )?;
+  /// assert_eq!(csl, CslVec::new([2, 3], vec![7, 1, 3], vec![1, 2, 0], vec![0, 2, 3])?);
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn from_raw_unsorted(
+    dims: [usize; D],
+    data: Vec<DATA>,
+    indcs: Vec<usize>,
+    offs: Vec<usize>,
+    dup_policy: impl FnMut(&mut DATA, DATA),
+  ) -> crate::Result<Self> {
+    Self::do_from_raw_unsorted(dims, data, indcs, offs, dup_policy, |lines| {
+      lines.into_iter().for_each(|(line_data, line_indcs)| sort_line(line_data, line_indcs));
+    })
+  }
+
+  /// Parallel version of [`from_raw_unsorted`](#method.from_raw_unsorted) that sorts lines
+  /// using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::csl::CslVec;
+  /// let csl = CslVec::par_from_raw_unsorted(
+  ///   [2, 3],
+  ///   vec![1, 2, 5, 3],
+  ///   vec![2, 1, 1, 0],
+  ///   vec![0, 3, 4],
+  ///   |prev, dup| *prev = dup,
+  /// )?;
+  /// assert_eq!(csl, CslVec::new([2, 3], vec![5, 1, 3], vec![1, 2, 0], vec![0, 2, 3])?);
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_from_raw_unsorted(
+    dims: [usize; D],
+    data: Vec<DATA>,
+    indcs: Vec<usize>,
+    offs: Vec<usize>,
+    dup_policy: impl FnMut(&mut DATA, DATA),
+  ) -> crate::Result<Self>
+  where
+    DATA: Send,
+  {
+    use rayon::prelude::*;
+    Self::do_from_raw_unsorted(dims, data, indcs, offs, dup_policy, |lines| {
+      lines.into_par_iter().for_each(|(line_data, line_indcs)| sort_line(line_data, line_indcs));
+    })
+  }
+
+  fn do_from_raw_unsorted(
+    dims: [usize; D],
+    mut data: Vec<DATA>,
+    mut indcs: Vec<usize>,
+    mut offs: Vec<usize>,
+    mut dup_policy: impl FnMut(&mut DATA, DATA),
+    sort_lines: impl FnOnce(Lines<'_, DATA>),
+  ) -> crate::Result<Self> {
+    if data.len() != indcs.len() {
+      return Err(CslError::DiffDataIndcsLength.into());
+    }
+    if !are_in_ascending_order(&offs, |a, b| [a, b]) {
+      return Err(CslError::InvalidOffsetsOrder.into());
+    }
+    let [first_off, last_off] = [offs.first(), offs.last()].map(|elem| elem.copied().unwrap_or(0));
+    if last_off.checked_sub(first_off) != Some(data.len()) {
+      return Err(CslError::LastOffsetDifferentNnz.into());
+    }
+
+    let mut lines = Vec::with_capacity(offs.len().saturating_sub(1));
+    let mut data_rest = data.as_mut_slice();
+    let mut indcs_rest = indcs.as_mut_slice();
+    for [a, b] in windows2(&offs) {
+      let len = b.checked_sub(*a).ok_or(crate::Error::UnknownError)?;
+      let (line_data, next_data_rest) =
+        data_rest.split_at_mut_checked(len).ok_or(crate::Error::UnknownError)?;
+      let (line_indcs, next_indcs_rest) =
+        indcs_rest.split_at_mut_checked(len).ok_or(crate::Error::UnknownError)?;
+      lines.push((line_data, line_indcs));
+      data_rest = next_data_rest;
+      indcs_rest = next_indcs_rest;
+    }
+    sort_lines(lines);
+
+    let mut new_data = Vec::with_capacity(data.len());
+    let mut new_indcs = Vec::with_capacity(indcs.len());
+    let mut iter = data.into_iter().zip(indcs);
+    let mut prev_off = first_off;
+    for off in &mut offs {
+      let line_len = off.saturating_sub(prev_off);
+      prev_off = *off;
+      let line_start = new_indcs.len();
+      for (value, idx) in iter.by_ref().take(line_len) {
+        let is_dup = new_indcs.len() > line_start && new_indcs.last() == Some(&idx);
+        match new_data.last_mut() {
+          Some(prev) if is_dup => dup_policy(prev, value),
+          _ => {
+            new_data.push(value);
+            new_indcs.push(idx);
+          }
+        }
+      }
+      *off = new_indcs.len();
+    }
+    Self::new_located(dims, new_data, new_indcs, offs)
+  }
+}
+
+// Stable in-place sort of a line by its indices.
+fn sort_line<DATA>(data: &mut [DATA], indcs: &mut [usize]) {
+  if are_in_ascending_order(indcs, |a, b| [a, b]) {
+    return;
+  }
+  let mut perm: Vec<usize> = (0..indcs.len()).collect();
+  perm.sort_by_key(|pos| indcs.get(*pos).copied());
+  // Follows the cycles of the permutation where the element located at `perm[pos]` must be moved
+  // to `pos`.
+  for start in 0..perm.len() {
+    let mut curr = start;
+    while let Some(next) = perm.get(curr).copied().filter(|next| *next != start) {
+      data.swap(curr, next);
+      indcs.swap(curr, next);
+      if let Some(elem) = perm.get_mut(curr) {
+        *elem = curr;
+      }
+      curr = next;
+    }
+    if let Some(elem) = perm.get_mut(curr) {
+      *elem = curr;
+    }
+  }
+}
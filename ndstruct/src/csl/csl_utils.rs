@@ -1,4 +1,7 @@
-use crate::csl::{Csl, CslError, CslMut, CslRef};
+use crate::{
+  csl::{Csl, CslError, CslMut, CslRef},
+  utils::linear_idx_to_indcs,
+};
 use cl_aux::{ArrayWrapper, Push};
 use core::ops::Range;
 
@@ -139,6 +142,16 @@ where
   }
 }
 
+// Indices of all dimensions of the line located at `line_idx`. The innermost index is zero.
+#[inline]
+pub(crate) fn line_coords<const D: usize>(dims: &[usize; D], line_idx: usize) -> [usize; D] {
+  let mut outer_dims = *dims;
+  if let Some(innermost_dim) = outer_dims.last_mut() {
+    *innermost_dim = 0;
+  }
+  linear_idx_to_indcs(&outer_dims, line_idx)
+}
+
 #[inline]
 pub(crate) fn line_offs<const D: usize>(
   dims: &[usize; D],
@@ -187,11 +200,58 @@ pub(crate) fn outermost_offs<const D: usize>(
   [start_off_idx..end_off_idx.saturating_add(1), off_start..off_end]
 }
 
+// Start of the `part`-th of `parts` contiguous pieces of `len` elements whose lengths differ by
+// at most one.
+#[cfg(feature = "rayon")]
+#[inline]
+pub(crate) fn balanced_split(len: usize, parts: usize, part: usize) -> usize {
+  if part >= parts {
+    return len;
+  }
+  let quot = len.checked_div(parts).unwrap_or(0);
+  let rem = len.checked_rem(parts).unwrap_or(0);
+  let rem_share = rem.saturating_mul(part).checked_div(parts).unwrap_or(0);
+  quot.saturating_mul(part).saturating_add(rem_share)
+}
+
+// Index of the first outermost line, within `0..=lines`, whose starting offset is greater than
+// or equal to `off`.
+#[cfg(feature = "rayon")]
+#[inline]
+pub(crate) fn outermost_line_by_off<const D: usize>(
+  dims: &[usize; D],
+  offs: &[usize],
+  lines: usize,
+  off: usize,
+) -> usize {
+  let outermost_stride = outermost_stride(dims);
+  let [mut low, mut high] = [0, lines];
+  while low < high {
+    let mid = low.saturating_add(high.wrapping_sub(low) / 2);
+    if offs.get(outermost_stride.saturating_mul(mid)).is_some_and(|elem| *elem < off) {
+      low = mid.saturating_add(1);
+    } else {
+      high = mid;
+    }
+  }
+  low
+}
+
 #[inline]
 pub(crate) fn outermost_stride<const D: usize>(dims: &[usize; D]) -> usize {
   dims.iter().skip(1).rev().skip(1).product::<usize>()
 }
 
+#[inline]
+pub(crate) fn located(
+  err: CslError,
+  line: Option<usize>,
+  pos: usize,
+  values: [usize; 2],
+) -> crate::Error {
+  crate::Error::CslAt(err, crate::Location { axis: None, line, pos, values: Some(values) })
+}
+
 #[inline]
 pub(crate) fn manage_last_offset<OS>(offs: &mut OS) -> crate::Result<usize>
 where
@@ -4,11 +4,17 @@
 //! if 2d, then this structure is the same as a matrix; if 3d, then this structure is the same as
 //! a cube and so on for higher dimensions.
 
+mod dense_broadcast;
 mod dense_error;
+mod dense_linalg;
 
+#[cfg(feature = "simd")]
+use crate::simd::{SimdFloat, ZipOp};
+use crate::utils::{dims_product, linear_idx_to_indcs};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use cl_aux::{ArrayWrapper, SingleTypeStorage};
+use cl_aux::{ArrayWrapper, DynContigColl, SingleTypeStorage};
+pub use dense_broadcast::DenseBroadcast;
 pub use dense_error::*;
 
 /// Dense backed by a static array.
@@ -28,6 +34,13 @@ pub type DenseVec<DATA, const D: usize> = Dense<Vec<DATA>, D>;
 /// * `D`: Number of dimensions
 /// * `DS`: Data Storage
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+  feature = "serde",
+  expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "Deserialized instances aren't validated and should be checked with `validate`"
+  )
+)]
 #[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Dense<DS, const D: usize> {
   pub(crate) data: DS,
@@ -47,6 +60,111 @@ impl<DS, const D: usize> Dense<DS, D> {
   pub fn dims(&self) -> &[usize; D] {
     &self.dims
   }
+
+  /// Reinterprets the current structure with a new set of dimensions without copying or
+  /// moving any data.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of new dimensions
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::doc_tests::dense_array_3;
+  /// let matrix = dense_array_3().reshape([12, 3])?;
+  /// assert_eq!(matrix.dims(), &[12, 3]);
+  /// assert_eq!(matrix.value([11, 2]), Some(&36));
+  /// let vector = matrix.reshape([36])?;
+  /// assert_eq!(vector.value([20]), Some(&21));
+  /// assert!(vector.reshape([5, 7]).is_err());
+  /// # Ok(()) }
+  /// ```
+  #[inline]
+  pub fn reshape<const E: usize>(self, dims: [usize; E]) -> crate::Result<Dense<DS, E>> {
+    let curr_len = dims_product(&self.dims);
+    if curr_len.is_none() || curr_len != dims_product(&dims) {
+      return Err(DenseError::InvalidReshapeDims.into());
+    }
+    Ok(Dense { data: self.data, dims: dims.into() })
+  }
+
+  // Number of elements of each sub-tensor formed by the dimensions located after `axis`
+  // alongside the dimensions of such sub-tensors.
+  #[cfg(feature = "rayon")]
+  fn axis_stride(&self, axis: usize) -> crate::Result<(usize, [usize; D])> {
+    if axis >= D {
+      return Err(DenseError::InvalidAxis.into());
+    }
+    let mut dims = self.dims.0;
+    dims.iter_mut().take(axis.saturating_add(1)).for_each(|dim| *dim = 1);
+    Ok((dims_product(&dims).unwrap_or(0).max(1), dims))
+  }
+
+  // Row-major position of `indcs`, if all indices are within the bounds of their dimensions.
+  //
+  // ((x * rows) + y) * cols + z
+  fn idx(&self, indcs: [usize; D]) -> Option<usize> {
+    self.dims.iter().zip(indcs).try_fold(0usize, |acc, (dim, idx)| {
+      if idx >= *dim {
+        return None;
+      }
+      acc.checked_mul(*dim)?.checked_add(idx)
+    })
+  }
+}
+
+impl<DATA, DS, const D: usize> Dense<DS, D>
+where
+  DS: DynContigColl<cl_aux::Error, DATA> + SingleTypeStorage<Item = DATA>,
+{
+  /// Creates an instance where every element is the result of `cb` applied to its indices.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `cb`: Callback that receives the indices of each element in storage order
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let dense = DenseVec::from_fn([2, 3], |[line, col]| line * 10 + col).unwrap();
+  /// assert_eq!(dense.data(), &[0, 1, 2, 10, 11, 12]);
+  /// ```
+  #[inline]
+  pub fn from_fn(dims: [usize; D], mut cb: impl FnMut([usize; D]) -> DATA) -> crate::Result<Self> {
+    let len = dims_product(&dims).ok_or(DenseError::DimsProductOverflow)?;
+    let mut data = DS::with_capacity(len)?;
+    data.extend((0..len).map(|idx| cb(linear_idx_to_indcs(&dims, idx))))?;
+    Ok(Self { data, dims: dims.into() })
+  }
+
+  /// Creates an instance where all elements are clones of `value`.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `value`: Element that fills the instance
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let dense = DenseVec::filled([2, 2], 'a').unwrap();
+  /// assert_eq!(dense.data(), &['a'; 4]);
+  /// ```
+  #[inline]
+  pub fn filled(dims: [usize; D], value: DATA) -> crate::Result<Self>
+  where
+    DATA: Clone,
+  {
+    let len = dims_product(&dims).ok_or(DenseError::DimsProductOverflow)?;
+    let mut data = DS::with_capacity(len)?;
+    data.extend(core::iter::repeat(value).take(len))?;
+    Ok(Self { data, dims: dims.into() })
+  }
 }
 
 impl<DATA, DS, const D: usize> Dense<DS, D>
@@ -72,10 +190,40 @@ where
   /// ```
   #[inline]
   pub fn new(dims: [usize; D], data: DS) -> crate::Result<Self> {
-    if dims.iter().copied().any(|elem| elem >= D) {
-      return Err(DenseError::InvalidIndcs.into());
-    }
-    Ok(Self { data, dims: dims.into() })
+    let rslt = Self { data, dims: dims.into() };
+    rslt.validate()?;
+    Ok(rslt)
+  }
+
+  /// Creates a [Dense] instance without validating the arguments in release builds.
+  ///
+  /// Useful for hot paths that build instances from parts that are already known to be valid.
+  /// Under `debug_assertions`, the arguments are fully validated and invalid instances panic.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `data`: Data collection
+  ///
+  /// # Safety
+  ///
+  /// The arguments must uphold all the invariants checked by [`validate`](#method.validate),
+  /// otherwise all other methods can return wrong results or panic.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::dense::DenseArray;
+  /// // SAFETY: Dimensions are valid
+  /// let dense = unsafe { DenseArray::new_unchecked([1, 1], [2]) };
+  /// assert_eq!(dense.value([0, 0]), Some(&2));
+  /// ```
+  #[expect(unsafe_code, reason = "The validation of the arguments is delegated to the caller")]
+  #[inline]
+  pub unsafe fn new_unchecked(dims: [usize; D], data: DS) -> Self {
+    let rslt = Self { data, dims: dims.into() };
+    debug_assert!(rslt.validate().is_ok(), "Invalid dense instance");
+    rslt
   }
 
   /// The data that is being stored.
@@ -96,6 +244,75 @@ where
     self.data.as_ref()
   }
 
+  /// Iterator that returns all elements in storage order alongside the indices of all
+  /// dimensions.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::doc_tests::dense_array_3;
+  /// let dense = dense_array_3();
+  /// let mut iter = dense.indexed_iter();
+  /// assert_eq!(iter.next(), Some(([0, 0, 0], &1)));
+  /// assert_eq!(iter.nth(13), Some(([1, 1, 2], &15)));
+  /// assert!(iter.all(|(indcs, elem)| dense.value(indcs) == Some(elem)));
+  /// ```
+  #[inline]
+  pub fn indexed_iter<'dense>(
+    &'dense self,
+  ) -> impl DoubleEndedIterator<Item = ([usize; D], &'dense DATA)>
+  where
+    DATA: 'dense,
+  {
+    let dims = self.dims.0;
+    self.data().iter().enumerate().map(move |(idx, elem)| (linear_idx_to_indcs(&dims, idx), elem))
+  }
+
+  /// Vectorized dot product between the elements of `self` and `rhs` in storage order.
+  ///
+  /// Returns [`DenseError::DiffDims`] if both instances don't have the same dimensions.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(feature = "simd", doc = "```rust")]
+  #[cfg_attr(not(feature = "simd"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseArray;
+  /// let lhs = DenseArray::new([2, 2, 2], core::array::from_fn::<f64, 8, _>(|idx| idx as f64)).unwrap();
+  /// let rhs = DenseArray::new([2, 2, 2], [2.0; 8]).unwrap();
+  /// let scalar: f64 = lhs.data().iter().zip(rhs.data()).map(|(a, b)| a * b).sum();
+  /// assert_eq!(lhs.simd_dot(&rhs), Ok(scalar));
+  /// ```
+  #[cfg(feature = "simd")]
+  #[inline]
+  pub fn simd_dot<RDS>(&self, rhs: &Dense<RDS, D>) -> crate::Result<DATA>
+  where
+    DATA: SimdFloat,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    if self.dims != rhs.dims {
+      return Err(DenseError::DiffDims.into());
+    }
+    Ok(DATA::dot(self.data(), rhs.data()))
+  }
+
+  /// Checks all the invariants of a valid instance and returns the first violation, if any.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::dense::DenseArray;
+  /// let dense = DenseArray::new([10, 10], [1; 100]).unwrap();
+  /// assert_eq!(dense.validate(), Ok(()));
+  /// ```
+  #[inline]
+  pub fn validate(&self) -> crate::Result<()> {
+    let len = dims_product(&self.dims.0).ok_or(DenseError::DimsProductOverflow)?;
+    if self.data().len() != len {
+      return Err(DenseError::DataLenDiffDimsProduct.into());
+    }
+    Ok(())
+  }
+
   /// If any, retrieves an immutable data reference of a given set of indices.
   ///
   /// # Arguments
@@ -112,24 +329,297 @@ where
   /// assert_eq!(elem.value([0, 2, 2]), Some(&9));
   /// assert_eq!(elem.value([3, 2, 2]), Some(&36));
   /// assert_eq!(elem.value([3, 2, 3]), None);
+  /// assert_eq!(elem.value([0, 0, 5]), None);
   /// ```
   #[inline]
   pub fn value(&self, indcs: [usize; D]) -> Option<&DATA> {
-    self.data().get(self.idx(indcs))
+    self.data().get(self.idx(indcs)?)
   }
 
-  // 1 * rows * cols * z
-  // 1 * rows        * y
-  // 1               * x
-  fn dim_stride(&self, dim_idx: usize, target_dim_idx: usize) -> usize {
-    self.dims.iter().copied().skip(1).rev().skip(dim_idx).chain([target_dim_idx]).product()
+  /// Parallel iterator that returns immutable sub-tensors of all index combinations of the
+  /// dimensions up to and including `axis` using `rayon`.
+  ///
+  /// # Arguments
+  ///
+  /// * `axis`: Index of the last fixed dimension
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::doc_tests::dense_array_3;
+  /// use rayon::prelude::*;
+  /// let dense = dense_array_3();
+  /// let lines: Vec<_> = dense.par_axis_iter(1)?.collect();
+  /// assert_eq!(lines.len(), 12);
+  /// assert_eq!(lines[4].dims(), &[1, 1, 3]);
+  /// assert_eq!(lines[4].data(), &[13, 14, 15]);
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_axis_iter<'dense>(
+    &'dense self,
+    axis: usize,
+  ) -> crate::Result<impl rayon::iter::IndexedParallelIterator<Item = DenseRef<'dense, DATA, D>>>
+  where
+    DATA: Sync + 'dense,
+  {
+    use rayon::prelude::*;
+    let (stride, dims) = self.axis_stride(axis)?;
+    Ok(self.data().par_chunks(stride).map(move |data| Dense { data, dims: dims.into() }))
   }
 
-  fn idx(&self, indcs: [usize; D]) -> usize {
-    let mut rslt: usize = 0;
-    for (dim_idx, target_dim_idx) in (0..self.dims.len()).zip(indcs) {
-      rslt = rslt.wrapping_add(self.dim_stride(dim_idx, target_dim_idx));
+  /// Parallel iterator that returns immutable sub-tensors of up to `lines` outermost lines
+  /// using `rayon`.
+  ///
+  /// # Arguments
+  ///
+  /// * `lines`: Maximum number of outermost lines of each sub-tensor
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// # fn main() -> ndstruct::Result<()> {
+  /// use ndstruct::doc_tests::dense_array_3;
+  /// use rayon::prelude::*;
+  /// let dense = dense_array_3();
+  /// let chunks: Vec<_> = dense.par_chunks(3)?.collect();
+  /// assert_eq!(chunks.iter().map(|chunk| chunk.dims()[0]).collect::<Vec<_>>(), [3, 1]);
+  /// assert_eq!(chunks[1].value([0, 2, 2]), Some(&36));
+  /// # Ok(()) }
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_chunks<'dense>(
+    &'dense self,
+    lines: usize,
+  ) -> crate::Result<impl rayon::iter::IndexedParallelIterator<Item = DenseRef<'dense, DATA, D>>>
+  where
+    DATA: Sync + 'dense,
+  {
+    use rayon::prelude::*;
+    if lines == 0 {
+      return Err(DenseError::InvalidChunks.into());
     }
-    rslt
+    let (stride, dims) = self.axis_stride(0)?;
+    Ok(
+      self
+        .data()
+        .par_chunks(stride.saturating_mul(lines))
+        .map(move |data| Dense { data, dims: chunk_dims(dims, data.len(), stride).into() }),
+    )
+  }
+
+  /// Parallel version of [`indexed_iter`](#method.indexed_iter) using `rayon`.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::doc_tests::dense_array_3;
+  /// use rayon::prelude::*;
+  /// let dense = dense_array_3();
+  /// let elems: Vec<_> = dense.par_indexed_iter().collect();
+  /// assert_eq!(elems, dense.indexed_iter().collect::<Vec<_>>());
+  /// ```
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_indexed_iter<'dense>(
+    &'dense self,
+  ) -> impl rayon::iter::IndexedParallelIterator<Item = ([usize; D], &'dense DATA)>
+  where
+    DATA: Sync + 'dense,
+  {
+    use rayon::prelude::*;
+    let dims = self.dims.0;
+    self
+      .data()
+      .par_iter()
+      .enumerate()
+      .map(move |(idx, elem)| (linear_idx_to_indcs(&dims, idx), elem))
+  }
+}
+
+impl<DATA, DS, const D: usize> Dense<DS, D>
+where
+  DS: AsMut<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = DATA>,
+{
+  /// Mutable version of [`data`](#method.data).
+  #[inline]
+  pub fn data_mut(&mut self) -> &mut [DATA] {
+    self.data.as_mut()
+  }
+
+  /// Mutable version of [`indexed_iter`](#method.indexed_iter).
+  #[inline]
+  pub fn indexed_iter_mut<'dense>(
+    &'dense mut self,
+  ) -> impl DoubleEndedIterator<Item = ([usize; D], &'dense mut DATA)>
+  where
+    DATA: 'dense,
+  {
+    let dims = self.dims.0;
+    self
+      .data
+      .as_mut()
+      .iter_mut()
+      .enumerate()
+      .map(move |(idx, elem)| (linear_idx_to_indcs(&dims, idx), elem))
+  }
+
+  /// Mutable version of [`par_indexed_iter`](#method.par_indexed_iter).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_indexed_iter_mut<'dense>(
+    &'dense mut self,
+  ) -> impl rayon::iter::IndexedParallelIterator<Item = ([usize; D], &'dense mut DATA)>
+  where
+    DATA: Send + 'dense,
+  {
+    use rayon::prelude::*;
+    let dims = self.dims.0;
+    self
+      .data
+      .as_mut()
+      .par_iter_mut()
+      .enumerate()
+      .map(move |(idx, elem)| (linear_idx_to_indcs(&dims, idx), elem))
+  }
+
+  /// Mutable version of [`par_axis_iter`](#method.par_axis_iter).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_axis_iter_mut<'dense>(
+    &'dense mut self,
+    axis: usize,
+  ) -> crate::Result<impl rayon::iter::IndexedParallelIterator<Item = DenseMut<'dense, DATA, D>>>
+  where
+    DATA: Send + 'dense,
+  {
+    use rayon::prelude::*;
+    let (stride, dims) = self.axis_stride(axis)?;
+    Ok(self.data.as_mut().par_chunks_mut(stride).map(move |data| Dense { data, dims: dims.into() }))
+  }
+
+  /// Mutable version of [`par_chunks`](#method.par_chunks).
+  #[cfg(feature = "rayon")]
+  #[inline]
+  pub fn par_chunks_mut<'dense>(
+    &'dense mut self,
+    lines: usize,
+  ) -> crate::Result<impl rayon::iter::IndexedParallelIterator<Item = DenseMut<'dense, DATA, D>>>
+  where
+    DATA: Send + 'dense,
+  {
+    use rayon::prelude::*;
+    if lines == 0 {
+      return Err(DenseError::InvalidChunks.into());
+    }
+    let (stride, dims) = self.axis_stride(0)?;
+    Ok(self.data.as_mut().par_chunks_mut(stride.saturating_mul(lines)).map(move |data| {
+      let len = data.len();
+      Dense { data, dims: chunk_dims(dims, len, stride).into() }
+    }))
+  }
+
+  /// Vectorized element-wise addition that stores `self + rhs` into `self`.
+  ///
+  /// Returns [`DenseError::DiffDims`] if both instances don't have the same dimensions.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(feature = "simd", doc = "```rust")]
+  #[cfg_attr(not(feature = "simd"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseArray;
+  /// let data = core::array::from_fn::<f32, 8, _>(|idx| idx as f32);
+  /// let mut lhs = DenseArray::new([2, 2, 2], data).unwrap();
+  /// lhs.simd_add_assign(&DenseArray::new([2, 2, 2], data).unwrap()).unwrap();
+  /// assert_eq!(lhs.data(), &data.map(|elem| elem + elem));
+  /// ```
+  #[cfg(feature = "simd")]
+  #[inline]
+  pub fn simd_add_assign<RDS>(&mut self, rhs: &Dense<RDS, D>) -> crate::Result<()>
+  where
+    DATA: SimdFloat,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    self.simd_zip_assign(rhs, ZipOp::Add)
+  }
+
+  /// Vectorized element-wise multiplication that stores `self * rhs` into `self`.
+  ///
+  /// Returns [`DenseError::DiffDims`] if both instances don't have the same dimensions.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(feature = "simd", doc = "```rust")]
+  #[cfg_attr(not(feature = "simd"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseArray;
+  /// let data = core::array::from_fn::<f64, 8, _>(|idx| idx as f64);
+  /// let mut lhs = DenseArray::new([2, 2, 2], data).unwrap();
+  /// lhs.simd_mul_assign(&DenseArray::new([2, 2, 2], data).unwrap()).unwrap();
+  /// assert_eq!(lhs.data(), &data.map(|elem| elem * elem));
+  /// ```
+  #[cfg(feature = "simd")]
+  #[inline]
+  pub fn simd_mul_assign<RDS>(&mut self, rhs: &Dense<RDS, D>) -> crate::Result<()>
+  where
+    DATA: SimdFloat,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    self.simd_zip_assign(rhs, ZipOp::Mul)
+  }
+
+  /// Vectorized element-wise subtraction that stores `self - rhs` into `self`.
+  ///
+  /// Returns [`DenseError::DiffDims`] if both instances don't have the same dimensions.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(feature = "simd", doc = "```rust")]
+  #[cfg_attr(not(feature = "simd"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseArray;
+  /// let data = core::array::from_fn::<f32, 8, _>(|idx| idx as f32);
+  /// let mut lhs = DenseArray::new([2, 2, 2], data).unwrap();
+  /// lhs.simd_sub_assign(&DenseArray::new([2, 2, 2], [1.0; 8]).unwrap()).unwrap();
+  /// assert_eq!(lhs.data(), &data.map(|elem| elem - 1.0));
+  /// ```
+  #[cfg(feature = "simd")]
+  #[inline]
+  pub fn simd_sub_assign<RDS>(&mut self, rhs: &Dense<RDS, D>) -> crate::Result<()>
+  where
+    DATA: SimdFloat,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    self.simd_zip_assign(rhs, ZipOp::Sub)
+  }
+
+  /// Mutable version of [`value`](#method.value).
+  #[inline]
+  pub fn value_mut(&mut self, indcs: [usize; D]) -> Option<&mut DATA> {
+    let idx = self.idx(indcs)?;
+    self.data.as_mut().get_mut(idx)
+  }
+
+  #[cfg(feature = "simd")]
+  fn simd_zip_assign<RDS>(&mut self, rhs: &Dense<RDS, D>, op: ZipOp) -> crate::Result<()>
+  where
+    DATA: SimdFloat,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    if self.dims != rhs.dims {
+      return Err(DenseError::DiffDims.into());
+    }
+    DATA::zip_assign(self.data.as_mut(), rhs.data(), op);
+    Ok(())
+  }
+}
+
+// Dimensions of a chunk of `len` elements composed by outermost lines of `stride` elements.
+#[cfg(feature = "rayon")]
+fn chunk_dims<const D: usize>(mut dims: [usize; D], len: usize, stride: usize) -> [usize; D] {
+  if let Some(first) = dims.first_mut() {
+    *first = len.checked_div(stride).unwrap_or(0);
   }
+  dims
 }
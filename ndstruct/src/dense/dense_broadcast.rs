@@ -0,0 +1,230 @@
+#[cfg(feature = "alloc")]
+use crate::dense::DenseVec;
+use crate::{
+  dense::{Dense, DenseError},
+  utils::{dims_product, linear_idx_to_indcs},
+};
+use cl_aux::{DynContigColl, SingleTypeStorage};
+#[cfg(feature = "alloc")]
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Immutable view of a [`Dense`] broadcasted to other dimensions.
+///
+/// Follows the rules of `NumPy`, i.e., dimensions are aligned from the innermost to the
+/// outermost and every dimension of length one is repeated to match the target length.
+///
+/// See [`Dense::broadcast_to`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DenseBroadcast<'data, DATA, const D: usize> {
+  data: &'data [DATA],
+  dims: [usize; D],
+  strides: [usize; D],
+}
+
+impl<'data, DATA, const D: usize> DenseBroadcast<'data, DATA, D> {
+  /// The definitions of all dimensions.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::dense::DenseArray;
+  /// let dense = DenseArray::new([1, 2], [1, 2]).unwrap();
+  /// assert_eq!(dense.broadcast_to([3, 2, 2]).unwrap().dims(), &[3, 2, 2]);
+  /// ```
+  #[inline]
+  pub fn dims(&self) -> &[usize; D] {
+    &self.dims
+  }
+
+  /// Iterator that returns all elements in row-major order, including repeated elements.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::dense::DenseArray;
+  /// let dense = DenseArray::new([2, 1], [1, 2]).unwrap();
+  /// let broadcast = dense.broadcast_to([2, 3]).unwrap();
+  /// assert!(broadcast.iter().eq(&[1, 1, 1, 2, 2, 2]));
+  /// ```
+  #[inline]
+  pub fn iter(&self) -> impl Iterator<Item = &'data DATA> + '_ {
+    let len = dims_product(&self.dims).unwrap_or(0);
+    (0..len).filter_map(|idx| self.value(linear_idx_to_indcs(&self.dims, idx)))
+  }
+
+  /// If any, retrieves an immutable data reference of a given set of indices.
+  ///
+  /// # Arguments
+  ///
+  /// * `indcs`: Indices of the desired data location
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::dense::DenseArray;
+  /// let dense = DenseArray::new([3], [1, 2, 3]).unwrap();
+  /// let broadcast = dense.broadcast_to([2, 3]).unwrap();
+  /// assert_eq!(broadcast.value([1, 2]), Some(&3));
+  /// assert_eq!(broadcast.value([2, 0]), None);
+  /// ```
+  #[inline]
+  pub fn value(&self, indcs: [usize; D]) -> Option<&'data DATA> {
+    let mut idx: usize = 0;
+    for ((target_idx, dim), stride) in indcs.into_iter().zip(self.dims).zip(self.strides) {
+      if target_idx >= dim {
+        return None;
+      }
+      idx = idx.checked_add(target_idx.checked_mul(stride)?)?;
+    }
+    self.data.get(idx)
+  }
+}
+
+impl<DATA, DS, const D: usize> Dense<DS, D>
+where
+  DS: AsRef<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = DATA>,
+{
+  /// Broadcasts `self` to `dims` without copying any element.
+  ///
+  /// `E` must be greater than or equal to `D` and every dimension of `self` must be equal to
+  /// its respective innermost-aligned dimension of `dims` or be equal to one, otherwise
+  /// [`DenseError::InvalidBroadcastDims`] is returned.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of target dimensions
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::dense::{DenseArray, DenseError};
+  /// let dense = DenseArray::new([1, 3], [1, 2, 3]).unwrap();
+  /// let broadcast = dense.broadcast_to([2, 2, 3]).unwrap();
+  /// assert!(broadcast.iter().eq([1, 2, 3].iter().cycle().take(12)));
+  /// assert_eq!(
+  ///   dense.broadcast_to([3, 2]),
+  ///   Err(ndstruct::Error::Dense(DenseError::InvalidBroadcastDims))
+  /// );
+  /// ```
+  #[inline]
+  pub fn broadcast_to<const E: usize>(
+    &self,
+    dims: [usize; E],
+  ) -> crate::Result<DenseBroadcast<'_, DATA, E>> {
+    let offset = E.checked_sub(D).ok_or(DenseError::InvalidBroadcastDims)?;
+    let mut strides = [0; E];
+    let mut stride: usize = 1;
+    for (idx, dim) in self.dims.iter().copied().enumerate().rev() {
+      let target_idx = idx.wrapping_add(offset);
+      let target_dim = dims.get(target_idx).copied().ok_or(crate::Error::UnknownError)?;
+      if dim != target_dim && dim != 1 {
+        return Err(DenseError::InvalidBroadcastDims.into());
+      }
+      if dim == target_dim {
+        *strides.get_mut(target_idx).ok_or(crate::Error::UnknownError)? = stride;
+      }
+      stride = stride.checked_mul(dim).ok_or(DenseError::DimsProductOverflow)?;
+    }
+    Ok(DenseBroadcast { data: self.data(), dims, strides })
+  }
+
+  /// Applies `cb` to every pair of elements of `self` and `rhs` broadcasted to their common
+  /// dimensions.
+  ///
+  /// Dimensions are aligned from the innermost to the outermost and missing outermost
+  /// dimensions are treated as one, which means that instances of different ranks can be
+  /// combined as long as the output rank `F` isn't smaller than any of them. Two dimensions are
+  /// compatible if they are equal or if one of them is equal to one, otherwise
+  /// [`DenseError::InvalidBroadcastDims`] is returned.
+  ///
+  /// # Arguments
+  ///
+  /// * `rhs`: Right-hand side instance
+  /// * `cb`: Callback that receives the elements of `self` and `rhs`
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::{DenseArray, DenseError, DenseVec};
+  /// let col = DenseArray::new([2, 1], [1, 2]).unwrap();
+  /// let line = DenseArray::new([1, 3], [1, 2, 3]).unwrap();
+  /// let dense: DenseVec<bool, 2> = col.zip_with(&line, |a, b| a < b).unwrap();
+  /// assert_eq!(dense.dims(), &[2, 3]);
+  /// assert_eq!(dense.data(), &[false, true, true, false, false, true]);
+  /// // Arithmetic operators of references use the same rules
+  /// let sum = (&col + &line).unwrap();
+  /// assert_eq!(sum.data(), &[2, 3, 4, 3, 4, 5]);
+  /// // Instances of different ranks
+  /// let vector = DenseArray::new([3], [10, 20, 30]).unwrap();
+  /// let matrix = DenseArray::new([2, 3], [1, 2, 3, 4, 5, 6]).unwrap();
+  /// let dense: DenseVec<i32, 2> = vector.zip_with(&matrix, |a, b| a + b).unwrap();
+  /// assert_eq!(dense.data(), &[11, 22, 33, 14, 25, 36]);
+  /// let rslt: ndstruct::Result<DenseVec<i32, 1>> = vector.zip_with(&matrix, |a, b| a + b);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Dense(DenseError::InvalidBroadcastDims)));
+  /// ```
+  #[inline]
+  pub fn zip_with<ODS, R, RDATA, RDS, const E: usize, const F: usize>(
+    &self,
+    rhs: &Dense<RDS, E>,
+    mut cb: impl FnMut(&DATA, &RDATA) -> R,
+  ) -> crate::Result<Dense<ODS, F>>
+  where
+    ODS: DynContigColl<cl_aux::Error, R> + SingleTypeStorage<Item = R>,
+    RDS: AsRef<[RDATA]> + SingleTypeStorage<Item = RDATA>,
+  {
+    let lhs_offset = F.checked_sub(D).ok_or(DenseError::InvalidBroadcastDims)?;
+    let rhs_offset = F.checked_sub(E).ok_or(DenseError::InvalidBroadcastDims)?;
+    let mut dims = [0; F];
+    for (idx, dim) in dims.iter_mut().enumerate() {
+      let lhs_dim =
+        idx.checked_sub(lhs_offset).and_then(|elem| self.dims.0.get(elem)).copied().unwrap_or(1);
+      let rhs_dim =
+        idx.checked_sub(rhs_offset).and_then(|elem| rhs.dims.0.get(elem)).copied().unwrap_or(1);
+      *dim = match (lhs_dim, rhs_dim) {
+        _ if lhs_dim == rhs_dim => lhs_dim,
+        (1, _) => rhs_dim,
+        (_, 1) => lhs_dim,
+        _ => return Err(DenseError::InvalidBroadcastDims.into()),
+      };
+    }
+    let len = dims_product(&dims).ok_or(DenseError::DimsProductOverflow)?;
+    let (lhs_broadcast, rhs_broadcast) = (self.broadcast_to(dims)?, rhs.broadcast_to(dims)?);
+    let mut data = ODS::with_capacity(len)?;
+    data.extend(
+      lhs_broadcast
+        .iter()
+        .zip(rhs_broadcast.iter())
+        .map(|(lhs_elem, rhs_elem)| cb(lhs_elem, rhs_elem)),
+    )?;
+    if data.len() != len {
+      return Err(crate::Error::UnknownError);
+    }
+    Ok(Dense { data, dims: dims.into() })
+  }
+}
+
+macro_rules! impl_op {
+  ($trait:ident, $method:ident) => {
+    /// See [`Dense::zip_with`]. Both operands must have the same rank, use
+    /// [`Dense::zip_with`] to combine instances of different ranks.
+    #[cfg(feature = "alloc")]
+    impl<DATA, LDS, RDS, const D: usize> $trait<&Dense<RDS, D>> for &Dense<LDS, D>
+    where
+      DATA: Copy + $trait<Output = DATA>,
+      LDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+      RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+    {
+      type Output = crate::Result<DenseVec<DATA, D>>;
+
+      #[inline]
+      fn $method(self, rhs: &Dense<RDS, D>) -> Self::Output {
+        self.zip_with(rhs, |lhs_elem, rhs_elem| lhs_elem.$method(*rhs_elem))
+      }
+    }
+  };
+}
+
+impl_op!(Add, add);
+impl_op!(Div, div);
+impl_op!(Mul, mul);
+impl_op!(Sub, sub);
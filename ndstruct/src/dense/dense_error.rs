@@ -4,14 +4,142 @@ use core::fmt::{Debug, Display, Formatter};
 #[derive(Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum DenseError {
-  /// Some index overflows the maximum number of dimensions.
+  /// The length of the data collection must be equal to the product of all dimensions
   ///
   /// ```rust
   /// use ndstruct::dense::{DenseArray, DenseError};
   /// let dense = DenseArray::new([2, 3], [1, 2, 3, 4]);
-  /// assert_eq!(dense, Err(ndstruct::Error::Dense(DenseError::InvalidIndcs)));
+  /// assert_eq!(dense, Err(ndstruct::Error::Dense(DenseError::DataLenDiffDimsProduct)));
+  /// ```
+  DataLenDiffDimsProduct,
+
+  /// Element-wise operations must deal with instances that have the same dimensions
+  ///
+  #[cfg_attr(feature = "simd", doc = "```rust")]
+  #[cfg_attr(not(feature = "simd"), doc = "```ignore")]
+  /// use ndstruct::dense::{DenseArray, DenseError};
+  /// let mut lhs = DenseArray::new([1, 1], [1.0]).unwrap();
+  /// let rhs = DenseArray::new([0, 0], [0.0; 0]).unwrap();
+  /// assert_eq!(lhs.simd_add_assign(&rhs), Err(ndstruct::Error::Dense(DenseError::DiffDims)));
+  /// ```
+  #[cfg(feature = "simd")]
+  DiffDims,
+
+  /// The product of all dimensions overflows the pointer size
+  ///
+  /// ```rust
+  /// use ndstruct::dense::{DenseError, DenseRef};
+  /// let dense = DenseRef::<(), 2>::new([usize::MAX, 2], &[]);
+  /// assert_eq!(dense, Err(ndstruct::Error::Dense(DenseError::DimsProductOverflow)));
+  /// ```
+  DimsProductOverflow,
+
+  /// The dimensions of the operands aren't compatible with the requested operation
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::{DenseError, DenseVec};
+  /// let lhs = DenseVec::<i32, 2>::ones([2, 3]).unwrap();
+  /// let mut out = DenseVec::default();
+  /// assert_eq!(
+  ///   lhs.matmul(&lhs, &mut out),
+  ///   Err(ndstruct::Error::Dense(DenseError::IncompatibleDims))
+  /// );
+  /// ```
+  IncompatibleDims,
+
+  /// The axis doesn't refer to an existing dimension
+  ///
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::{dense::DenseError, doc_tests::dense_array_3};
+  /// let dense = dense_array_3();
+  /// assert_eq!(dense.par_axis_iter(3).err(), Some(ndstruct::Error::Dense(DenseError::InvalidAxis)));
+  /// ```
+  #[cfg(feature = "rayon")]
+  InvalidAxis,
+
+  /// Dimensions can't be broadcasted to each other
+  ///
+  /// ```rust
+  /// use ndstruct::dense::{DenseArray, DenseError};
+  /// let dense = DenseArray::new([2], [1, 2]).unwrap();
+  /// let rslt = dense.broadcast_to([2, 3]);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Dense(DenseError::InvalidBroadcastDims)));
   /// ```
+  InvalidBroadcastDims,
+
+  /// Chunk iterators must deal with a non-zero number of lines per chunk
+  ///
+  #[cfg_attr(all(feature = "alloc", feature = "rayon"), doc = "```rust")]
+  #[cfg_attr(not(all(feature = "alloc", feature = "rayon")), doc = "```ignore")]
+  /// use ndstruct::{dense::DenseError, doc_tests::dense_array_3};
+  /// let dense = dense_array_3();
+  /// assert_eq!(dense.par_chunks(0).err(), Some(ndstruct::Error::Dense(DenseError::InvalidChunks)));
+  /// ```
+  #[cfg(feature = "rayon")]
+  InvalidChunks,
+
+  /// Some index overflows the maximum number of dimensions.
+  ///
+  /// No longer returned. Instances whose number of elements is different than the product of
+  /// their dimensions are rejected with [`DenseError::DataLenDiffDimsProduct`].
+  #[deprecated(note = "Replaced by `DenseError::DataLenDiffDimsProduct`")]
   InvalidIndcs,
+
+  /// The new dimensions don't describe the same number of elements of the current dimensions
+  ///
+  /// ```rust
+  /// use ndstruct::{dense::DenseError, doc_tests::dense_array_3};
+  /// let dense = dense_array_3().reshape([4, 10]);
+  /// assert_eq!(dense, Err(ndstruct::Error::Dense(DenseError::InvalidReshapeDims)));
+  /// ```
+  InvalidReshapeDims,
+
+  /// Square matrices are required
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::{DenseError, DenseVec};
+  /// let dense = DenseVec::<f64, 2>::ones([2, 3]).unwrap();
+  /// let (mut out, mut perm) = (DenseVec::default(), Vec::new());
+  /// assert_eq!(
+  ///   dense.lu(&mut out, &mut perm),
+  ///   Err(ndstruct::Error::Dense(DenseError::NonSquareMatrix))
+  /// );
+  /// ```
+  NonSquareMatrix,
+
+  /// The Cholesky decomposition requires symmetric positive-definite matrices
+  ///
+  #[cfg_attr(all(feature = "alloc", any(feature = "libm", feature = "std")), doc = "```rust")]
+  #[cfg_attr(
+    not(all(feature = "alloc", any(feature = "libm", feature = "std"))),
+    doc = "```ignore"
+  )]
+  /// use ndstruct::dense::{DenseError, DenseVec};
+  /// let dense = DenseVec::<f64, 2>::zeros([2, 2]).unwrap();
+  /// let mut out = DenseVec::default();
+  /// assert_eq!(
+  ///   dense.cholesky(&mut out),
+  ///   Err(ndstruct::Error::Dense(DenseError::NotPositiveDefinite))
+  /// );
+  /// ```
+  NotPositiveDefinite,
+
+  /// The matrix doesn't have an inverse, i.e., some pivot is zero
+  ///
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::{DenseError, DenseVec};
+  /// let dense = DenseVec::<f64, 2>::ones([2, 2]).unwrap();
+  /// let (mut out, mut perm) = (DenseVec::default(), Vec::new());
+  /// assert_eq!(
+  ///   dense.lu(&mut out, &mut perm),
+  ///   Err(ndstruct::Error::Dense(DenseError::SingularMatrix))
+  /// );
+  /// ```
+  SingularMatrix,
 }
 
 impl Display for DenseError {
@@ -0,0 +1,642 @@
+use crate::{
+  dense::{Dense, DenseError},
+  num::{Abs, Num, One, Sqrt, Zero},
+  utils::dims_product,
+};
+use cl_aux::{DynContigColl, SingleTypeStorage};
+use core::{cmp::Ordering, ops::Div};
+
+// Number of lines and columns of the square blocks used by matrix multiplications.
+const GEMM_BLOCK: usize = 64;
+
+impl<DATA, DS, const D: usize> Dense<DS, D>
+where
+  DS: DynContigColl<cl_aux::Error, DATA> + SingleTypeStorage<Item = DATA>,
+{
+  /// Creates an instance where all elements are equal to one.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let dense = DenseVec::<u8, 2>::ones([2, 3]).unwrap();
+  /// assert_eq!(dense.data(), &[1; 6]);
+  /// ```
+  #[inline]
+  pub fn ones(dims: [usize; D]) -> crate::Result<Self>
+  where
+    DATA: One,
+  {
+    Self::from_fn(dims, |_| DATA::one())
+  }
+
+  /// Creates an instance where all elements are equal to zero.
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let dense = DenseVec::<f32, 3>::zeros([2, 1, 2]).unwrap();
+  /// assert_eq!(dense.data(), &[0.0; 4]);
+  /// ```
+  #[inline]
+  pub fn zeros(dims: [usize; D]) -> crate::Result<Self>
+  where
+    DATA: Zero,
+  {
+    Self::from_fn(dims, |_| DATA::zero())
+  }
+}
+
+impl<DATA, DS> Dense<DS, 2>
+where
+  DS: DynContigColl<cl_aux::Error, DATA> + SingleTypeStorage<Item = DATA>,
+{
+  /// Creates a square matrix with ones on the main diagonal and zeros elsewhere.
+  ///
+  /// # Arguments
+  ///
+  /// * `dim`: Number of lines and columns
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let dense = DenseVec::<i32, 2>::identity(2).unwrap();
+  /// assert_eq!(dense.data(), &[1, 0, 0, 1]);
+  /// ```
+  #[inline]
+  pub fn identity(dim: usize) -> crate::Result<Self>
+  where
+    DATA: One + Zero,
+  {
+    let mut rslt = Self::zeros([dim, dim])?;
+    for elem in rslt.data.iter_mut().step_by(dim.saturating_add(1)) {
+      *elem = DATA::one();
+    }
+    Ok(rslt)
+  }
+}
+
+impl<DATA, DS> Dense<DS, 2>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+{
+  /// Cholesky decomposition of a symmetric positive-definite matrix.
+  ///
+  /// Writes into `out` the lower triangular matrix `L` where `self = L * Lᵀ`. Only the lower
+  /// triangle of `self` is read.
+  ///
+  /// # Arguments
+  ///
+  /// * `out`: Output matrix
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", any(feature = "libm", feature = "std")), doc = "```rust")]
+  #[cfg_attr(
+    not(all(feature = "alloc", any(feature = "libm", feature = "std"))),
+    doc = "```ignore"
+  )]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<f64, 2>::zeros([2, 2]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[4.0, 2.0, 2.0, 10.0]);
+  /// let mut out = DenseVec::default();
+  /// dense.cholesky(&mut out).unwrap();
+  /// assert_eq!(out.data(), &[2.0, 0.0, 1.0, 3.0]);
+  /// ```
+  #[inline]
+  pub fn cholesky<ODS>(&self, out: &mut Dense<ODS, 2>) -> crate::Result<()>
+  where
+    DATA: Div<Output = DATA> + Num + PartialOrd + Sqrt,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+  {
+    let dim = square_dim(self.dims.0)?;
+    fill(out, self.dims.0, self.data().iter().copied())?;
+    cholesky(&mut out.data, dim)
+  }
+
+  /// LU decomposition with partial pivoting.
+  ///
+  /// Writes into `out` both factors of `P * self = L * U`, where the strictly lower triangle
+  /// contains `L` without its unit diagonal and the upper triangle contains `U`. The row
+  /// permutation `P` is written into `perm`, i.e., the line `idx` of `P * self` is the line
+  /// `perm[idx]` of `self`.
+  ///
+  /// # Arguments
+  ///
+  /// * `out`: Output matrix
+  /// * `perm`: Output permutation
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<f64, 2>::zeros([2, 2]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[1.0, 2.0, 4.0, 4.0]);
+  /// let (mut out, mut perm) = (DenseVec::default(), Vec::new());
+  /// dense.lu(&mut out, &mut perm).unwrap();
+  /// assert_eq!(out.data(), &[4.0, 4.0, 0.25, 1.0]);
+  /// assert_eq!(perm, [1, 0]);
+  /// ```
+  #[inline]
+  pub fn lu<ODS, PS>(&self, out: &mut Dense<ODS, 2>, perm: &mut PS) -> crate::Result<()>
+  where
+    DATA: Abs + Div<Output = DATA> + Num,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+    PS: DynContigColl<cl_aux::Error, usize>,
+    <DATA as Abs>::Output: PartialOrd,
+  {
+    let dim = square_dim(self.dims.0)?;
+    fill(out, self.dims.0, self.data().iter().copied())?;
+    perm.clear();
+    perm.extend(0..dim)?;
+    lu(&mut out.data, perm, dim)
+  }
+
+  /// Blocked matrix multiplication that writes `self * rhs` into `out`.
+  ///
+  /// # Arguments
+  ///
+  /// * `rhs`: Right-hand side matrix
+  /// * `out`: Output matrix
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut lhs = DenseVec::<i32, 2>::zeros([2, 3]).unwrap();
+  /// lhs.data_mut().copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+  /// let rhs = DenseVec::<i32, 2>::ones([3, 1]).unwrap();
+  /// let mut out = DenseVec::default();
+  /// lhs.matmul(&rhs, &mut out).unwrap();
+  /// assert_eq!(out.dims(), &[2, 1]);
+  /// assert_eq!(out.data(), &[6, 15]);
+  /// ```
+  #[inline]
+  pub fn matmul<ODS, RDS>(&self, rhs: &Dense<RDS, 2>, out: &mut Dense<ODS, 2>) -> crate::Result<()>
+  where
+    DATA: Num,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    let ([lines, inner], [rhs_inner, cols]) = (self.dims.0, rhs.dims.0);
+    if inner != rhs_inner {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    fill(out, [lines, cols], core::iter::repeat_with(DATA::zero))?;
+    gemm(self.data(), rhs.data(), &mut out.data, [inner, cols]);
+    Ok(())
+  }
+
+  /// Matrix-vector multiplication that writes `self * x` into `y`.
+  ///
+  /// # Arguments
+  ///
+  /// * `x`: Vector with the same length of the number of columns
+  /// * `y`: Output vector
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<i32, 2>::zeros([2, 2]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[1, 2, 3, 4]);
+  /// let mut y = Vec::new();
+  /// dense.matvec(&[1, 10], &mut y).unwrap();
+  /// assert_eq!(y, [21, 43]);
+  /// ```
+  #[inline]
+  pub fn matvec<YS>(&self, x: &[DATA], y: &mut YS) -> crate::Result<()>
+  where
+    DATA: Num,
+    YS: DynContigColl<cl_aux::Error, DATA>,
+  {
+    let [lines, cols] = self.dims.0;
+    if x.len() != cols {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    y.clear();
+    y.extend(gemv(self.data(), x, [lines, cols]))?;
+    Ok(())
+  }
+
+  /// Writes the transpose of `self` into `out`.
+  ///
+  /// # Arguments
+  ///
+  /// * `out`: Output matrix
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<i32, 2>::zeros([2, 3]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+  /// let mut out = DenseVec::default();
+  /// dense.transpose(&mut out).unwrap();
+  /// assert_eq!(out.dims(), &[3, 2]);
+  /// assert_eq!(out.data(), &[1, 4, 2, 5, 3, 6]);
+  /// ```
+  #[inline]
+  pub fn transpose<ODS>(&self, out: &mut Dense<ODS, 2>) -> crate::Result<()>
+  where
+    DATA: Clone,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+  {
+    let [lines, cols] = self.dims.0;
+    fill(out, [cols, lines], transposed(self.data(), [lines, cols]))
+  }
+}
+
+impl<DATA, DS> Dense<DS, 3>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+{
+  /// Batched version of [`cholesky`](Dense::cholesky) where the outermost dimension indexes
+  /// independent matrices.
+  ///
+  /// # Example
+  #[cfg_attr(all(feature = "alloc", any(feature = "libm", feature = "std")), doc = "```rust")]
+  #[cfg_attr(
+    not(all(feature = "alloc", any(feature = "libm", feature = "std"))),
+    doc = "```ignore"
+  )]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<f64, 3>::zeros([2, 1, 1]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[4.0, 9.0]);
+  /// let mut out = DenseVec::default();
+  /// dense.batched_cholesky(&mut out).unwrap();
+  /// assert_eq!(out.data(), &[2.0, 3.0]);
+  /// ```
+  #[inline]
+  pub fn batched_cholesky<ODS>(&self, out: &mut Dense<ODS, 3>) -> crate::Result<()>
+  where
+    DATA: Div<Output = DATA> + Num + PartialOrd + Sqrt,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+  {
+    let [batches, lines, cols] = self.dims.0;
+    let dim = square_dim([lines, cols])?;
+    fill(out, self.dims.0, self.data().iter().copied())?;
+    for batch in 0..batches {
+      cholesky(matrix_mut(&mut out.data, dims_product(&[dim, dim]), batch)?, dim)?;
+    }
+    Ok(())
+  }
+
+  /// Batched version of [`lu`](Dense::lu) where the outermost dimension indexes independent
+  /// matrices. The permutations of all matrices are sequentially written into `perm`.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<f64, 3>::zeros([2, 2, 2]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[1.0, 2.0, 4.0, 4.0, 2.0, 0.0, 1.0, 1.0]);
+  /// let (mut out, mut perm) = (DenseVec::default(), Vec::new());
+  /// dense.batched_lu(&mut out, &mut perm).unwrap();
+  /// assert_eq!(out.data(), &[4.0, 4.0, 0.25, 1.0, 2.0, 0.0, 0.5, 1.0]);
+  /// assert_eq!(perm, [1, 0, 0, 1]);
+  /// ```
+  #[inline]
+  pub fn batched_lu<ODS, PS>(&self, out: &mut Dense<ODS, 3>, perm: &mut PS) -> crate::Result<()>
+  where
+    DATA: Abs + Div<Output = DATA> + Num,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+    PS: DynContigColl<cl_aux::Error, usize>,
+    <DATA as Abs>::Output: PartialOrd,
+  {
+    let [batches, lines, cols] = self.dims.0;
+    let dim = square_dim([lines, cols])?;
+    fill(out, self.dims.0, self.data().iter().copied())?;
+    perm.clear();
+    perm.extend((0..batches).flat_map(|_| 0..dim))?;
+    for batch in 0..batches {
+      let matrix_data = matrix_mut(&mut out.data, dims_product(&[dim, dim]), batch)?;
+      lu(matrix_data, matrix_mut(perm, Some(dim), batch)?, dim)?;
+    }
+    Ok(())
+  }
+
+  /// Batched version of [`matmul`](Dense::matmul) where the outermost dimension indexes
+  /// independent matrices.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut lhs = DenseVec::<i32, 3>::zeros([2, 1, 2]).unwrap();
+  /// lhs.data_mut().copy_from_slice(&[1, 2, 3, 4]);
+  /// let rhs = DenseVec::<i32, 3>::ones([2, 2, 1]).unwrap();
+  /// let mut out = DenseVec::default();
+  /// lhs.batched_matmul(&rhs, &mut out).unwrap();
+  /// assert_eq!(out.dims(), &[2, 1, 1]);
+  /// assert_eq!(out.data(), &[3, 7]);
+  /// ```
+  #[inline]
+  pub fn batched_matmul<ODS, RDS>(
+    &self,
+    rhs: &Dense<RDS, 3>,
+    out: &mut Dense<ODS, 3>,
+  ) -> crate::Result<()>
+  where
+    DATA: Num,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+    RDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  {
+    let ([batches, lines, inner], [rhs_batches, rhs_inner, cols]) = (self.dims.0, rhs.dims.0);
+    if batches != rhs_batches || inner != rhs_inner {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    fill(out, [batches, lines, cols], core::iter::repeat_with(DATA::zero))?;
+    for batch in 0..batches {
+      let lhs_data = matrix(self.data(), dims_product(&[lines, inner]), batch)?;
+      let rhs_data = matrix(rhs.data(), dims_product(&[inner, cols]), batch)?;
+      let out_data = matrix_mut(&mut out.data, dims_product(&[lines, cols]), batch)?;
+      gemm(lhs_data, rhs_data, out_data, [inner, cols]);
+    }
+    Ok(())
+  }
+
+  /// Batched version of [`matvec`](Dense::matvec) where the outermost dimension indexes
+  /// independent matrices and each line of `x` is multiplied by its respective matrix.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<i32, 3>::zeros([2, 1, 2]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[1, 2, 3, 4]);
+  /// let mut x = DenseVec::<i32, 2>::zeros([2, 2]).unwrap();
+  /// x.data_mut().copy_from_slice(&[1, 10, 2, 20]);
+  /// let mut y = DenseVec::default();
+  /// dense.batched_matvec(&x, &mut y).unwrap();
+  /// assert_eq!(y.dims(), &[2, 1]);
+  /// assert_eq!(y.data(), &[21, 86]);
+  /// ```
+  #[inline]
+  pub fn batched_matvec<XDS, YDS>(
+    &self,
+    x: &Dense<XDS, 2>,
+    y: &mut Dense<YDS, 2>,
+  ) -> crate::Result<()>
+  where
+    DATA: Num,
+    XDS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+    YDS: DynContigColl<cl_aux::Error, DATA>,
+  {
+    let ([batches, lines, cols], [x_batches, x_len]) = (self.dims.0, x.dims.0);
+    if batches != x_batches || cols != x_len {
+      return Err(DenseError::IncompatibleDims.into());
+    }
+    let (data, x_data) = (self.data(), x.data());
+    let matrix_len = dims_product(&[lines, cols]);
+    let iter = (0..batches).flat_map(|batch| {
+      let matrix_data = matrix(data, matrix_len, batch).unwrap_or_default();
+      let vector = matrix(x_data, Some(cols), batch).unwrap_or_default();
+      gemv(matrix_data, vector, [lines, cols])
+    });
+    fill(y, [batches, lines], iter)
+  }
+
+  /// Batched version of [`transpose`](Dense::transpose) where the outermost dimension indexes
+  /// independent matrices.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::dense::DenseVec;
+  /// let mut dense = DenseVec::<i32, 3>::zeros([2, 1, 2]).unwrap();
+  /// dense.data_mut().copy_from_slice(&[1, 2, 3, 4]);
+  /// let mut out = DenseVec::default();
+  /// dense.batched_transpose(&mut out).unwrap();
+  /// assert_eq!(out.dims(), &[2, 2, 1]);
+  /// assert_eq!(out.data(), &[1, 2, 3, 4]);
+  /// ```
+  #[inline]
+  pub fn batched_transpose<ODS>(&self, out: &mut Dense<ODS, 3>) -> crate::Result<()>
+  where
+    DATA: Clone,
+    ODS: DynContigColl<cl_aux::Error, DATA>,
+  {
+    let [batches, lines, cols] = self.dims.0;
+    let data = self.data();
+    let matrix_len = dims_product(&[lines, cols]);
+    let iter = (0..batches).flat_map(|batch| {
+      transposed(matrix(data, matrix_len, batch).unwrap_or_default(), [lines, cols])
+    });
+    fill(out, [batches, cols, lines], iter)
+  }
+}
+
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+fn cholesky<DATA>(data: &mut [DATA], dim: usize) -> crate::Result<()>
+where
+  DATA: Div<Output = DATA> + Num + PartialOrd + Sqrt,
+{
+  for col in 0..dim {
+    let rest = data.get_mut(col.saturating_mul(dim)..).ok_or(crate::Error::UnknownError)?;
+    let (line, other_lines) = rest.split_at_mut_checked(dim).ok_or(crate::Error::UnknownError)?;
+    let (line_head, line_tail) =
+      line.split_at_mut_checked(col).ok_or(crate::Error::UnknownError)?;
+    let [diag_ref, upper @ ..] = line_tail else {
+      return Err(crate::Error::UnknownError);
+    };
+    let sum = *diag_ref - dot(line_head, line_head);
+    if sum.partial_cmp(&DATA::zero()) != Some(Ordering::Greater) {
+      return Err(DenseError::NotPositiveDefinite.into());
+    }
+    let diag = sum.sqrt_value();
+    *diag_ref = diag;
+    upper.iter_mut().for_each(|elem| *elem = DATA::zero());
+    for other_line in other_lines.chunks_exact_mut(dim) {
+      let (other_head, other_tail) =
+        other_line.split_at_mut_checked(col).ok_or(crate::Error::UnknownError)?;
+      let elem = other_tail.first_mut().ok_or(crate::Error::UnknownError)?;
+      *elem = (*elem - dot(other_head, line_head)) / diag;
+    }
+  }
+  Ok(())
+}
+
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+fn dot<DATA>(a: &[DATA], b: &[DATA]) -> DATA
+where
+  DATA: Num,
+{
+  a.iter().zip(b).fold(DATA::zero(), |acc, (lhs, rhs)| acc + *lhs * *rhs)
+}
+
+// Replaces the elements and the dimensions of `out`.
+fn fill<DATA, ODS, const D: usize>(
+  out: &mut Dense<ODS, D>,
+  dims: [usize; D],
+  iter: impl IntoIterator<Item = DATA>,
+) -> crate::Result<()>
+where
+  ODS: DynContigColl<cl_aux::Error, DATA>,
+{
+  let len = dims_product(&dims).ok_or(DenseError::DimsProductOverflow)?;
+  out.data.clear();
+  out.data.extend(iter.into_iter().take(len))?;
+  if out.data.len() != len {
+    return Err(crate::Error::UnknownError);
+  }
+  out.dims = dims.into();
+  Ok(())
+}
+
+// `a` has `b.len()` columns and `c` has `cols` columns.
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+fn gemm<DATA>(a: &[DATA], b: &[DATA], c: &mut [DATA], [inner, cols]: [usize; 2])
+where
+  DATA: Num,
+{
+  if inner == 0 || cols == 0 {
+    return;
+  }
+  let a_blocks = a.chunks(GEMM_BLOCK.saturating_mul(inner));
+  for (c_block, a_block) in c.chunks_mut(GEMM_BLOCK.saturating_mul(cols)).zip(a_blocks) {
+    let b_blocks = b.chunks(GEMM_BLOCK.saturating_mul(cols));
+    for (inner_start, b_block) in (0..inner).step_by(GEMM_BLOCK).zip(b_blocks) {
+      let inner_range = inner_start..inner_start.saturating_add(GEMM_BLOCK).min(inner);
+      for cols_start in (0..cols).step_by(GEMM_BLOCK) {
+        let cols_range = cols_start..cols_start.saturating_add(GEMM_BLOCK).min(cols);
+        for (c_line, a_line) in c_block.chunks_exact_mut(cols).zip(a_block.chunks_exact(inner)) {
+          let c_slice = c_line.get_mut(cols_range.clone()).unwrap_or_default();
+          let a_slice = a_line.get(inner_range.clone()).unwrap_or_default();
+          for (a_elem, b_line) in a_slice.iter().zip(b_block.chunks_exact(cols)) {
+            let b_slice = b_line.get(cols_range.clone()).unwrap_or_default();
+            for (c_elem, b_elem) in c_slice.iter_mut().zip(b_slice) {
+              *c_elem += *a_elem * *b_elem;
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+fn gemv<'slices, DATA>(
+  a: &'slices [DATA],
+  x: &'slices [DATA],
+  [lines, cols]: [usize; 2],
+) -> impl Iterator<Item = DATA> + 'slices
+where
+  DATA: Num,
+{
+  (0..lines).map(move |line| {
+    let start = line.saturating_mul(cols);
+    dot(a.get(start..start.saturating_add(cols)).unwrap_or_default(), x)
+  })
+}
+
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+fn lu<DATA>(data: &mut [DATA], perm: &mut [usize], dim: usize) -> crate::Result<()>
+where
+  DATA: Abs + Div<Output = DATA> + Num,
+  <DATA as Abs>::Output: PartialOrd,
+{
+  for col in 0..dim {
+    let mut pivot_line = col;
+    let mut pivot_abs = None;
+    for line in col..dim {
+      let idx = line.saturating_mul(dim).saturating_add(col);
+      let abs = data.get(idx).ok_or(crate::Error::UnknownError)?.abs_value();
+      if pivot_abs.as_ref().map_or(true, |elem| abs > *elem) {
+        pivot_abs = Some(abs);
+        pivot_line = line;
+      }
+    }
+    swap_lines(data, dim, col, pivot_line)?;
+    swap_lines(perm, 1, col, pivot_line)?;
+    let lines_end = col.saturating_add(1).saturating_mul(dim);
+    let (head, other_lines) =
+      data.split_at_mut_checked(lines_end).ok_or(crate::Error::UnknownError)?;
+    let line = head.get(col.saturating_mul(dim)..).ok_or(crate::Error::UnknownError)?;
+    let (pivot, line_tail) = match line.get(col..) {
+      Some([pivot, line_tail @ ..]) => (*pivot, line_tail),
+      _ => return Err(crate::Error::UnknownError),
+    };
+    if pivot.is_zero() {
+      return Err(DenseError::SingularMatrix.into());
+    }
+    for other_line in other_lines.chunks_exact_mut(dim) {
+      let Some([factor_ref, other_tail @ ..]) = other_line.get_mut(col..) else {
+        return Err(crate::Error::UnknownError);
+      };
+      let factor = *factor_ref / pivot;
+      *factor_ref = factor;
+      for (elem, line_elem) in other_tail.iter_mut().zip(line_tail) {
+        *elem = *elem - factor * *line_elem;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn matrix<T>(slice: &[T], len_opt: Option<usize>, idx: usize) -> crate::Result<&[T]> {
+  let len = len_opt.ok_or(DenseError::IncompatibleDims)?;
+  let start = len.saturating_mul(idx);
+  slice.get(start..start.saturating_add(len)).ok_or(crate::Error::UnknownError)
+}
+
+fn matrix_mut<T>(slice: &mut [T], len_opt: Option<usize>, idx: usize) -> crate::Result<&mut [T]> {
+  let len = len_opt.ok_or(DenseError::IncompatibleDims)?;
+  let start = len.saturating_mul(idx);
+  slice.get_mut(start..start.saturating_add(len)).ok_or(crate::Error::UnknownError)
+}
+
+fn square_dim([lines, cols]: [usize; 2]) -> crate::Result<usize> {
+  if lines != cols {
+    return Err(DenseError::NonSquareMatrix.into());
+  }
+  Ok(lines)
+}
+
+fn swap_lines<T>(slice: &mut [T], len: usize, a: usize, b: usize) -> crate::Result<()> {
+  let [first, second] = match a.cmp(&b) {
+    Ordering::Equal => return Ok(()),
+    Ordering::Greater => [b, a],
+    Ordering::Less => [a, b],
+  };
+  let (head, tail) =
+    slice.split_at_mut_checked(second.saturating_mul(len)).ok_or(crate::Error::UnknownError)?;
+  let start = first.saturating_mul(len);
+  let (Some(first_line), Some(second_line)) =
+    (head.get_mut(start..start.saturating_add(len)), tail.get_mut(..len))
+  else {
+    return Err(crate::Error::UnknownError);
+  };
+  first_line.swap_with_slice(second_line);
+  Ok(())
+}
+
+fn transposed<DATA>(data: &[DATA], [lines, cols]: [usize; 2]) -> impl Iterator<Item = DATA> + '_
+where
+  DATA: Clone,
+{
+  (0..cols).flat_map(move |col| {
+    (0..lines)
+      .filter_map(move |line| data.get(line.saturating_mul(cols).saturating_add(col)).cloned())
+  })
+}
@@ -0,0 +1,306 @@
+use crate::{coo::Coo, csl::Csl, dense::Dense, utils::nonzero_dims_product, NdStruct};
+use core::fmt::{Display, Formatter, Write};
+
+const DEFAULT_LIMIT: usize = 16;
+const DEFAULT_SPY_HEIGHT: usize = 32;
+const DEFAULT_SPY_WIDTH: usize = 64;
+// Horizontal ellipsis
+const ELLIPSIS: char = '\u{2026}';
+const MAX_SPY_HEIGHT: usize = 128;
+const MAX_SPY_WIDTH: usize = 128;
+// Full block
+const NNZ: char = '\u{2588}';
+// Middle dot
+const ZERO: char = '\u{b7}';
+
+/// Renders any [`NdStruct`] as aligned grids of elements.
+///
+/// One-dimensional structures are rendered as a single row, two-dimensional structures as a grid
+/// and higher dimensions as a sequence of grids, each one preceded by a header with the indices
+/// of the outermost dimensions. Structural zeros are rendered as `·` and any dimension longer
+/// than the current limit is truncated with `…`. Zero outermost dimensions are treated as one.
+///
+/// # Example
+#[cfg_attr(feature = "alloc", doc = "```rust")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use ndstruct::{
+///   csl::{CslArray, CslVec},
+///   NdStruct,
+/// };
+/// let csl = CslArray::new([2, 4], [1, 20, 3], [0, 2, 2], [0, 2, 3]).unwrap();
+/// assert_eq!(csl.display().to_string(), " 1  · 20  ·\n ·  ·  3  ·\n");
+/// assert_eq!(csl.display().limit(2).to_string(), " 1  · …\n ·  · …\n");
+///
+/// let dense = ndstruct::doc_tests::dense_array_3();
+/// assert_eq!(
+///   dense.display().limit(2).to_string(),
+///   "[0, :, :]\n 1  2 …\n 4  5 …\n…\n\n[1, :, :]\n10 11 …\n13 14 …\n…\n\n…\n"
+/// );
+///
+/// let empty = CslVec::<i32, 2>::new([0, 3], vec![], vec![], vec![0, 0]).unwrap();
+/// assert_eq!(empty.display().to_string(), "· · ·\n");
+/// let csl = CslVec::new([0, 2, 3], vec![7], vec![1], vec![0, 0, 1]).unwrap();
+/// assert_eq!(csl.display().to_string(), "[0, :, :]\n· · ·\n· 7 ·\n");
+/// ```
+#[derive(Debug)]
+pub struct NdDisplay<'any, T, const D: usize> {
+  elem: &'any T,
+  limit: usize,
+}
+
+impl<'any, T, const D: usize> NdDisplay<'any, T, D> {
+  #[inline]
+  pub(crate) fn new(elem: &'any T) -> Self {
+    Self { elem, limit: DEFAULT_LIMIT }
+  }
+
+  /// Maximum number of slices, rows and columns that will be rendered in each dimension.
+  /// Defaults to 16.
+  #[inline]
+  #[must_use]
+  pub fn limit(mut self, limit: usize) -> Self {
+    self.limit = limit;
+    self
+  }
+}
+
+impl<T, const D: usize> NdDisplay<'_, T, D>
+where
+  T: NdStruct<D>,
+  T::Data: Display,
+{
+  fn fmt_grid(
+    &self,
+    f: &mut Formatter<'_>,
+    mut indcs: [usize; D],
+    width: usize,
+  ) -> core::fmt::Result {
+    let dims = self.elem.dims();
+    let cols = dims.last().copied().unwrap_or(0);
+    let rows = if D == 1 {
+      1
+    } else {
+      D.checked_sub(2).and_then(|idx| dims.get(idx)).copied().map_or(0, |dim| dim.max(1))
+    };
+    for row in 0..rows {
+      if row >= self.limit {
+        f.write_char(ELLIPSIS)?;
+        f.write_char('\n')?;
+        break;
+      }
+      if let Some(elem) = D.checked_sub(2).and_then(|idx| indcs.get_mut(idx)) {
+        *elem = row;
+      }
+      for col in 0..cols {
+        if col > 0 {
+          f.write_char(' ')?;
+        }
+        if col >= self.limit {
+          f.write_char(ELLIPSIS)?;
+          break;
+        }
+        if let Some(elem) = indcs.last_mut() {
+          *elem = col;
+        }
+        match self.elem.value(indcs) {
+          Some(elem) => write_padded(f, elem, width)?,
+          None => write_padded(f, &ZERO, width)?,
+        }
+      }
+      f.write_char('\n')?;
+    }
+    Ok(())
+  }
+}
+
+impl<T, const D: usize> Display for NdDisplay<'_, T, D>
+where
+  T: NdStruct<D>,
+  T::Data: Display,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    let mut width = 1;
+    for (_, elem) in self.elem.iter_nnz() {
+      width = width.max(display_width(elem)?);
+    }
+    let Some(outer_dims) = D.checked_sub(2).and_then(|idx| self.elem.dims().get(..idx)) else {
+      return if D == 0 { Ok(()) } else { self.fmt_grid(f, [0; D], width) };
+    };
+    if D == 2 {
+      return self.fmt_grid(f, [0; D], width);
+    }
+    for slice_idx in 0..nonzero_dims_product(outer_dims).ok_or(core::fmt::Error)? {
+      if slice_idx > 0 {
+        f.write_char('\n')?;
+      }
+      if slice_idx >= self.limit {
+        f.write_char(ELLIPSIS)?;
+        f.write_char('\n')?;
+        break;
+      }
+      let mut indcs = [0; D];
+      let mut remaining = slice_idx;
+      for (elem, dim) in indcs.iter_mut().zip(outer_dims).rev().filter(|(_, dim)| **dim != 0) {
+        *elem = remaining.checked_rem(*dim).ok_or(core::fmt::Error)?;
+        remaining = remaining.checked_div(*dim).ok_or(core::fmt::Error)?;
+      }
+      f.write_char('[')?;
+      for elem in indcs.iter().take(outer_dims.len()) {
+        write!(f, "{elem}, ")?;
+      }
+      f.write_str(":, :]\n")?;
+      self.fmt_grid(f, indcs, width)?;
+    }
+    Ok(())
+  }
+}
+
+/// Renders the sparsity pattern of any [`NdStruct`] in a terminal.
+///
+/// All outermost dimensions are flattened into rows while the innermost dimension represents
+/// the columns. Big structures are downsampled into a grid of at most `height` rows and `width`
+/// columns where each cell is filled with `█` if any element falls into its region, otherwise
+/// `·`.
+///
+/// # Example
+#[cfg_attr(feature = "alloc", doc = "```rust")]
+#[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+/// use ndstruct::{doc_tests::csl_array_4, NdStruct};
+/// let csl = csl_array_4();
+/// assert_eq!(csl.spy().height(3).to_string(), "█████\n··█·█\n·····\n");
+/// assert_eq!(csl.spy().height(3).width(2).to_string(), "██\n██\n··\n");
+/// ```
+#[derive(Debug)]
+pub struct NdSpy<'any, T, const D: usize> {
+  elem: &'any T,
+  height: usize,
+  width: usize,
+}
+
+impl<'any, T, const D: usize> NdSpy<'any, T, D> {
+  #[inline]
+  pub(crate) fn new(elem: &'any T) -> Self {
+    Self { elem, height: DEFAULT_SPY_HEIGHT, width: DEFAULT_SPY_WIDTH }
+  }
+
+  /// Maximum number of rendered rows, up to 128. Defaults to 32.
+  #[inline]
+  #[must_use]
+  pub fn height(mut self, height: usize) -> Self {
+    self.height = height.min(MAX_SPY_HEIGHT);
+    self
+  }
+
+  /// Maximum number of rendered columns, up to 128. Defaults to 64.
+  #[inline]
+  #[must_use]
+  pub fn width(mut self, width: usize) -> Self {
+    self.width = width.min(MAX_SPY_WIDTH);
+    self
+  }
+}
+
+impl<T, const D: usize> Display for NdSpy<'_, T, D>
+where
+  T: NdStruct<D>,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    let dims = self.elem.dims();
+    let Some((cols, outer_dims)) = dims.split_last() else {
+      return Ok(());
+    };
+    let rows = nonzero_dims_product(outer_dims).ok_or(core::fmt::Error)?;
+    let height = self.height.min(rows);
+    let width = self.width.min(*cols);
+    // Each row of cells is a bit set where the bit located at `n` represents the column `n`.
+    let mut bitmap = [0u128; MAX_SPY_HEIGHT];
+    for (indcs, _) in self.elem.iter_nnz() {
+      let mut line: usize = 0;
+      for (idx, dim) in indcs.iter().zip(outer_dims).filter(|(_, dim)| **dim != 0) {
+        line = line.saturating_mul(*dim).saturating_add(*idx);
+      }
+      let col = indcs.last().copied().unwrap_or(0);
+      let Some(cells) = scale(line, rows, height).and_then(|idx| bitmap.get_mut(idx)) else {
+        continue;
+      };
+      let cell_col = scale(col, *cols, width).and_then(|idx| u32::try_from(idx).ok());
+      if let Some(bit) = cell_col.and_then(|shift| 1u128.checked_shl(shift)) {
+        *cells |= bit;
+      }
+    }
+    for cells in bitmap.iter().take(height) {
+      for shift in (0..u128::BITS).take(width) {
+        let is_nnz = cells.checked_shr(shift).is_some_and(|elem| elem & 1 == 1);
+        f.write_char(if is_nnz { NNZ } else { ZERO })?;
+      }
+      f.write_char('\n')?;
+    }
+    Ok(())
+  }
+}
+
+impl<DS, const D: usize> Display for Coo<DS, D>
+where
+  Self: NdStruct<D>,
+  <Self as NdStruct<D>>::Data: Display,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    NdDisplay::new(self).fmt(f)
+  }
+}
+
+impl<DS, IS, OS, const D: usize> Display for Csl<DS, IS, OS, D>
+where
+  Self: NdStruct<D>,
+  <Self as NdStruct<D>>::Data: Display,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    NdDisplay::new(self).fmt(f)
+  }
+}
+
+impl<DS, const D: usize> Display for Dense<DS, D>
+where
+  Self: NdStruct<D>,
+  <Self as NdStruct<D>>::Data: Display,
+{
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    NdDisplay::new(self).fmt(f)
+  }
+}
+
+struct WidthCounter(usize);
+
+impl Write for WidthCounter {
+  #[inline]
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    self.0 = self.0.saturating_add(s.chars().count());
+    Ok(())
+  }
+}
+
+fn display_width(elem: &impl Display) -> Result<usize, core::fmt::Error> {
+  let mut counter = WidthCounter(0);
+  write!(counter, "{elem}")?;
+  Ok(counter.0)
+}
+
+// Maps `idx` of a `len` sized dimension into a `cells` sized dimension.
+fn scale(idx: usize, len: usize, cells: usize) -> Option<usize> {
+  if len <= cells {
+    return Some(idx);
+  }
+  idx.checked_mul(cells)?.checked_div(len)
+}
+
+fn write_padded(f: &mut Formatter<'_>, elem: &impl Display, width: usize) -> core::fmt::Result {
+  for _ in display_width(elem)?..width {
+    f.write_char(' ')?;
+  }
+  write!(f, "{elem}")
+}
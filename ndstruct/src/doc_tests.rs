@@ -73,9 +73,10 @@ pub fn coo_vec_5() -> CooVec<i32, 5> {
   CooVec { dims: coo.dims, data: coo.data.to_vec() }
 }
 
-/// Two cuboids illustrating a [2, 3, 4, 5] 4D in a [w, y, z, x] order, i.e., each "line"
-/// or 1D representation is a left to right row and each "matrix" or 2D representation
-/// is filled in a top-down manner.
+/// Two cuboids illustrating a [2, 3, 4, 5] 4D in a [w, y, z, x] order.
+///
+/// Each "line" or 1D representation is a left to right row and each "matrix" or 2D
+/// representation is filled in a top-down manner.
 ///
 /// ```rust
 /// // w: left to right
@@ -1,3 +1,9 @@
+#[cfg(feature = "arrow")]
+use crate::arrow::ArrowError;
+#[cfg(feature = "graph")]
+use crate::graph::GraphError;
+#[cfg(feature = "std")]
+use crate::text::TextError;
 use crate::{
   coo::CooError,
   csl::{CslError, CslLineConstructorError},
@@ -9,22 +15,74 @@ use core::fmt::{Debug, Display, Formatter};
 #[derive(Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Error {
+  /// See [`ArrowError`]
+  #[cfg(feature = "arrow")]
+  Arrow(ArrowError),
   /// See [`cl_aux::Error`].
   ClAux(cl_aux::Error),
   /// See [`CooError`]
   Coo(CooError),
+  /// [`CooError`] alongside the [`Location`] of the offending element
+  CooAt(CooError, Location),
   /// See [`CslError`]
   Csl(CslError),
+  /// [`CslError`] alongside the [`Location`] of the offending element
+  CslAt(CslError, Location),
   /// [`CslLineConstructorError`]
   CslLineConstructor(CslLineConstructorError),
   /// See [`DenseError`]
   Dense(DenseError),
+  /// See [`GraphError`]
+  #[cfg(feature = "graph")]
+  Graph(GraphError),
   /// The internal buffer can't store all necessary data
   InsufficientCapacity,
+  /// See [`TextError`]
+  #[cfg(feature = "std")]
+  Text(TextError),
   /// An Unknown that probably shouldn't have happened
   UnknownError,
 }
 
+impl Error {
+  /// The location of the offending element, if any.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::{CslArray, CslError}, Error};
+  /// let err = CslArray::new_located([2, 3], [1, 2], [1, 1], [0, 0, 2]).unwrap_err();
+  /// assert_eq!(err.location().map(|location| (location.line, location.pos)), Some((Some(1), 1)));
+  /// assert_eq!(Error::Csl(CslError::DuplicatedIndices).location(), None);
+  /// ```
+  #[inline]
+  pub fn location(&self) -> Option<&Location> {
+    match self {
+      Self::CooAt(_, location) | Self::CslAt(_, location) => Some(location),
+      _ => None,
+    }
+  }
+
+  /// Discards the location of the offending element, if any.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::{CslArray, CslError}, Error};
+  /// let err = CslArray::new_located([2, 3], [1, 2], [1, 1], [0, 0, 2]).unwrap_err();
+  /// assert_eq!(err.without_location(), Error::Csl(CslError::DuplicatedIndices));
+  /// ```
+  #[inline]
+  #[must_use]
+  pub fn without_location(self) -> Self {
+    match self {
+      Self::CooAt(err, _) => Self::Coo(err),
+      Self::CslAt(err, _) => Self::Csl(err),
+      _ => self,
+    }
+  }
+}
+
 impl Display for Error {
   #[inline]
   fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
@@ -34,6 +92,14 @@ impl Display for Error {
 
 impl core::error::Error for Error {}
 
+#[cfg(feature = "arrow")]
+impl From<ArrowError> for Error {
+  #[inline]
+  fn from(from: ArrowError) -> Self {
+    Self::Arrow(from)
+  }
+}
+
 impl From<cl_aux::Error> for Error {
   #[inline]
   fn from(from: cl_aux::Error) -> Self {
@@ -68,3 +134,38 @@ impl From<DenseError> for Error {
     Self::Dense(from)
   }
 }
+
+#[cfg(feature = "graph")]
+impl From<GraphError> for Error {
+  #[inline]
+  fn from(from: GraphError) -> Self {
+    Self::Graph(from)
+  }
+}
+
+#[cfg(feature = "std")]
+impl From<TextError> for Error {
+  #[inline]
+  fn from(from: TextError) -> Self {
+    Self::Text(from)
+  }
+}
+
+/// Where an offending element of a structure is located.
+///
+/// Stored elements are identified by their position in the storage of data and indices while
+/// offsets are identified by their position in the storage of offsets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Location {
+  /// Dimension of the offending index, if the error is related to a single dimension.
+  pub axis: Option<usize>,
+  /// Innermost line that contains the offending element, if the structure has lines.
+  pub line: Option<usize>,
+  /// Position of the offending element.
+  pub pos: usize,
+  /// The offending value and the value it conflicts with, if any. Duplicated indices instead
+  /// report the position of the earlier duplicate followed by the position of the offending
+  /// element.
+  pub values: Option<[usize; 2]>,
+}
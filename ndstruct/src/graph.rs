@@ -0,0 +1,633 @@
+//! Graph
+//!
+//! Algorithms that interpret a 2-D [`Csl`] as the adjacency matrix of a graph, i.e., the element
+//! located at `[from, to]` is the weight of the edge that goes from `from` to `to`.
+
+mod graph_error;
+
+use crate::{
+  csl::{Csl, CslError},
+  num::Zero,
+};
+use alloc::{
+  collections::{BinaryHeap, VecDeque},
+  vec::Vec,
+};
+use cl_aux::SingleTypeStorage;
+#[cfg(feature = "rayon")]
+use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use core::{
+  cmp::Ordering,
+  ops::{Add, Range},
+};
+pub use graph_error::*;
+
+// Level of vertices that weren't visited by a parallel breadth-first search.
+#[cfg(feature = "rayon")]
+const UNVISITED: usize = usize::MAX;
+
+/// Vertices reachable from `source` in breadth-first order. Neighbors are visited in ascending
+/// order.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::bfs};
+/// // 0 -> 1, 0 -> 2, 1 -> 3, 3 -> 0
+/// let csl = CslArray::new([4, 4], [1, 1, 1, 1], [1, 2, 3, 0], [0, 2, 3, 3, 4])?;
+/// assert_eq!(bfs(&csl, 0)?, vec![0, 1, 2, 3]);
+/// assert_eq!(bfs(&csl, 2)?, vec![2]);
+/// # Ok(()) }
+/// ```
+#[inline]
+pub fn bfs<DATA, DS, IS, OS>(graph: &Csl<DS, IS, OS, 2>, source: usize) -> crate::Result<Vec<usize>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let vertices = vertices_with(graph, source)?;
+  let mut is_visited = alloc::vec![false; vertices];
+  let mut queue = VecDeque::from([source]);
+  let mut rslt = Vec::new();
+  set(&mut is_visited, source, true)?;
+  while let Some(vertex) = queue.pop_front() {
+    rslt.push(vertex);
+    for neighbor in neighbors(graph, vertex)?.0.iter().copied() {
+      if !get(&is_visited, neighbor)? {
+        set(&mut is_visited, neighbor, true)?;
+        queue.push_back(neighbor);
+      }
+    }
+  }
+  Ok(rslt)
+}
+
+/// Number of edges that separate each vertex from `source`. Unreachable vertices are `None`.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::bfs_levels};
+/// // 0 -> 1, 0 -> 2, 1 -> 3, 3 -> 0
+/// let csl = CslArray::new([4, 4], [1, 1, 1, 1], [1, 2, 3, 0], [0, 2, 3, 3, 4])?;
+/// assert_eq!(bfs_levels(&csl, 0)?, vec![Some(0), Some(1), Some(1), Some(2)]);
+/// assert_eq!(bfs_levels(&csl, 2)?, vec![None, None, Some(0), None]);
+/// # Ok(()) }
+/// ```
+#[inline]
+pub fn bfs_levels<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+  source: usize,
+) -> crate::Result<Vec<Option<usize>>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let vertices = vertices_with(graph, source)?;
+  let mut levels = alloc::vec![None; vertices];
+  let mut queue = VecDeque::from([(source, 0usize)]);
+  set(&mut levels, source, Some(0))?;
+  while let Some((vertex, level)) = queue.pop_front() {
+    let next_level = level.checked_add(1).ok_or(crate::Error::UnknownError)?;
+    for neighbor in neighbors(graph, vertex)?.0.iter().copied() {
+      if get(&levels, neighbor)?.is_none() {
+        set(&mut levels, neighbor, Some(next_level))?;
+        queue.push_back((neighbor, next_level));
+      }
+    }
+  }
+  Ok(levels)
+}
+
+/// Labels each vertex with the smallest vertex of its connected component. Edges are treated as
+/// undirected, which means that the returned components are weakly connected.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::connected_components};
+/// // 1 -> 3, 4 -> 2
+/// let csl = CslArray::new([5, 5], [1, 1], [3, 2], [0, 0, 1, 1, 1, 2])?;
+/// assert_eq!(connected_components(&csl)?, vec![0, 1, 2, 1, 2]);
+/// # Ok(()) }
+/// ```
+#[inline]
+pub fn connected_components<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+) -> crate::Result<Vec<usize>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let vertices = vertices(graph)?;
+  let mut parents: Vec<usize> = (0..vertices).collect();
+  for vertex in 0..vertices {
+    for neighbor in neighbors(graph, vertex)?.0.iter().copied() {
+      let [a, b] = [root(&mut parents, vertex)?, root(&mut parents, neighbor)?];
+      set(&mut parents, a.max(b), a.min(b))?;
+    }
+  }
+  for vertex in 0..vertices {
+    let parent = root(&mut parents, vertex)?;
+    set(&mut parents, vertex, parent)?;
+  }
+  Ok(parents)
+}
+
+/// Vertices reachable from `source` in depth-first pre-order. Neighbors are visited in ascending
+/// order.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::dfs};
+/// // 0 -> 1, 0 -> 2, 1 -> 3, 3 -> 0
+/// let csl = CslArray::new([4, 4], [1, 1, 1, 1], [1, 2, 3, 0], [0, 2, 3, 3, 4])?;
+/// assert_eq!(dfs(&csl, 0)?, vec![0, 1, 3, 2]);
+/// # Ok(()) }
+/// ```
+#[inline]
+pub fn dfs<DATA, DS, IS, OS>(graph: &Csl<DS, IS, OS, 2>, source: usize) -> crate::Result<Vec<usize>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let vertices = vertices_with(graph, source)?;
+  let mut is_visited = alloc::vec![false; vertices];
+  let mut stack = alloc::vec![source];
+  let mut rslt = Vec::new();
+  while let Some(vertex) = stack.pop() {
+    if get(&is_visited, vertex)? {
+      continue;
+    }
+    set(&mut is_visited, vertex, true)?;
+    rslt.push(vertex);
+    for neighbor in neighbors(graph, vertex)?.0.iter().rev().copied() {
+      if !get(&is_visited, neighbor)? {
+        stack.push(neighbor);
+      }
+    }
+  }
+  Ok(rslt)
+}
+
+/// Shortest distance of each vertex from `source` using the stored elements as non-negative
+/// weights. Unreachable vertices are `None`.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::dijkstra};
+/// // 0 -(4)-> 1, 0 -(1)-> 2, 2 -(2)-> 1
+/// let csl = CslArray::new([4, 4], [4, 1, 2], [1, 2, 1], [0, 2, 2, 3, 3])?;
+/// assert_eq!(dijkstra(&csl, 0)?, vec![Some(0), Some(3), Some(1), None]);
+/// # Ok(()) }
+/// ```
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the weight type"
+)]
+#[inline]
+pub fn dijkstra<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+  source: usize,
+) -> crate::Result<Vec<Option<DATA>>>
+where
+  DATA: Add<Output = DATA> + Copy + PartialOrd + Zero,
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let vertices = vertices_with(graph, source)?;
+  if graph.data().iter().any(|weight| *weight < DATA::zero()) {
+    return Err(GraphError::NegativeWeight.into());
+  }
+  let mut distances = alloc::vec![None; vertices];
+  let mut heap = BinaryHeap::from([MinDistance(DATA::zero(), source)]);
+  set(&mut distances, source, Some(DATA::zero()))?;
+  while let Some(MinDistance(distance, vertex)) = heap.pop() {
+    if get(&distances, vertex)?.is_some_and(|elem| distance > elem) {
+      continue;
+    }
+    let (indcs, weights) = neighbors(graph, vertex)?;
+    for (neighbor, weight) in indcs.iter().copied().zip(weights.iter().copied()) {
+      let new_distance = distance + weight;
+      if get(&distances, neighbor)?.map_or(true, |elem| new_distance < elem) {
+        set(&mut distances, neighbor, Some(new_distance))?;
+        heap.push(MinDistance(new_distance, neighbor));
+      }
+    }
+  }
+  Ok(distances)
+}
+
+/// Iteratively computes the `PageRank` of each vertex until the sum of all absolute differences
+/// between two iterations is less than `tolerance` or `max_iters` is reached.
+///
+/// Stored elements are treated as unweighted edges and vertices without outgoing edges
+/// distribute their rank evenly among all vertices.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::page_rank};
+/// // 0 -> 1, 1 -> 2, 2 -> 0
+/// let csl = CslArray::new([3, 3], [1, 1, 1], [1, 2, 0], [0, 1, 2, 3])?;
+/// for rank in page_rank(&csl, 0.85, 100, 1e-9)? {
+///   assert!((rank - 1.0 / 3.0).abs() < 1e-6);
+/// }
+/// # Ok(()) }
+/// ```
+#[expect(
+  clippy::as_conversions,
+  clippy::cast_precision_loss,
+  clippy::float_arithmetic,
+  reason = "`PageRank` is an inherently imprecise floating-point approximation"
+)]
+#[inline]
+pub fn page_rank<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+  damping: f64,
+  max_iters: usize,
+  tolerance: f64,
+) -> crate::Result<Vec<f64>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  if !(0.0..=1.0).contains(&damping) {
+    return Err(GraphError::InvalidDampingFactor.into());
+  }
+  let vertices = vertices(graph)?;
+  if vertices == 0 {
+    return Ok(Vec::new());
+  }
+  let vertices_f64 = vertices as f64;
+  let mut ranks = alloc::vec![1.0 / vertices_f64; vertices];
+  let mut next_ranks = alloc::vec![0.0; vertices];
+  for _ in 0..max_iters {
+    let mut dangling = 0.0;
+    for (vertex, rank) in ranks.iter().copied().enumerate() {
+      let indcs = neighbors(graph, vertex)?.0;
+      if indcs.is_empty() {
+        dangling += rank;
+      }
+    }
+    let base = (1.0 - damping + damping * dangling) / vertices_f64;
+    next_ranks.iter_mut().for_each(|elem| *elem = base);
+    for (vertex, rank) in ranks.iter().copied().enumerate() {
+      let indcs = neighbors(graph, vertex)?.0;
+      let share = damping * rank / indcs.len() as f64;
+      for neighbor in indcs.iter().copied() {
+        *next_ranks.get_mut(neighbor).ok_or(crate::Error::UnknownError)? += share;
+      }
+    }
+    let diff: f64 = ranks
+      .iter()
+      .zip(&next_ranks)
+      .map(|(prev, next)| if prev > next { prev - next } else { next - prev })
+      .sum();
+    core::mem::swap(&mut ranks, &mut next_ranks);
+    if diff < tolerance {
+      break;
+    }
+  }
+  Ok(ranks)
+}
+
+/// Parallel version of [`bfs_levels`] using `rayon`.
+///
+/// The outgoing edges of all vertices of each frontier are visited in parallel and every
+/// unvisited vertex is claimed by a single thread through an atomic compare-and-exchange.
+///
+/// # Example
+#[cfg_attr(feature = "rayon", doc = "```rust")]
+#[cfg_attr(not(feature = "rayon"), doc = "```ignore")]
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::{bfs_levels, par_bfs_levels}};
+/// // 0 -> 1, 0 -> 2, 1 -> 3, 3 -> 0
+/// let csl = CslArray::new([4, 4], [1, 1, 1, 1], [1, 2, 3, 0], [0, 2, 3, 3, 4])?;
+/// assert_eq!(par_bfs_levels(&csl, 0)?, bfs_levels(&csl, 0)?);
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "rayon")]
+#[inline]
+pub fn par_bfs_levels<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+  source: usize,
+) -> crate::Result<Vec<Option<usize>>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+  let vertices = vertices_with(graph, source)?;
+  let (indcs, offs) = (graph.indcs(), graph.offs());
+  let levels: Vec<_> = (0..vertices).map(|_| AtomicUsize::new(UNVISITED)).collect();
+  atomic(&levels, source)?.store(0, AtomicOrdering::Relaxed);
+  let mut frontier = alloc::vec![source];
+  let mut level: usize = 0;
+  while !frontier.is_empty() {
+    level = level.checked_add(1).ok_or(crate::Error::UnknownError)?;
+    frontier = frontier
+      .par_iter()
+      .flat_map_iter(|vertex| {
+        let (line, err) = match line_indcs(indcs, offs, *vertex) {
+          Ok(elem) => (elem, None),
+          Err(err) => (&[][..], Some(Err(err))),
+        };
+        let claimed = line.iter().copied().filter(|neighbor| {
+          levels.get(*neighbor).is_some_and(|elem| {
+            let rslt = elem.compare_exchange(
+              UNVISITED,
+              level,
+              AtomicOrdering::Relaxed,
+              AtomicOrdering::Relaxed,
+            );
+            rslt.is_ok()
+          })
+        });
+        err.into_iter().chain(claimed.map(Ok))
+      })
+      .collect::<crate::Result<_>>()?;
+  }
+  Ok(
+    levels
+      .into_iter()
+      .map(|atomic_level| Some(atomic_level.into_inner()).filter(|elem| *elem != UNVISITED))
+      .collect(),
+  )
+}
+
+/// Parallel version of [`connected_components`] using `rayon`.
+///
+/// All edges are merged in parallel by a lock-free union-find where roots are always linked to
+/// smaller roots, as such, each component is labeled by its smallest vertex.
+///
+/// # Example
+#[cfg_attr(feature = "rayon", doc = "```rust")]
+#[cfg_attr(not(feature = "rayon"), doc = "```ignore")]
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::{connected_components, par_connected_components}};
+/// // 1 -> 3, 4 -> 2
+/// let csl = CslArray::new([5, 5], [1, 1], [3, 2], [0, 0, 1, 1, 1, 2])?;
+/// assert_eq!(par_connected_components(&csl)?, vec![0, 1, 2, 1, 2]);
+/// assert_eq!(par_connected_components(&csl)?, connected_components(&csl)?);
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "rayon")]
+#[inline]
+pub fn par_connected_components<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+) -> crate::Result<Vec<usize>>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  use rayon::iter::{IntoParallelIterator, ParallelIterator};
+  let vertices = vertices(graph)?;
+  let (indcs, offs) = (graph.indcs(), graph.offs());
+  let parents: Vec<_> = (0..vertices).map(AtomicUsize::new).collect();
+  (0..vertices).into_par_iter().try_for_each(|vertex| {
+    for neighbor in line_indcs(indcs, offs, vertex)?.iter().copied() {
+      par_union(&parents, vertex, neighbor)?;
+    }
+    Ok::<_, crate::Error>(())
+  })?;
+  (0..vertices).into_par_iter().map(|vertex| par_root(&parents, vertex)).collect()
+}
+
+/// Number of triangles of an undirected graph, i.e., a graph with a symmetric adjacency matrix.
+///
+/// Only the strictly upper triangular part of the matrix is inspected, as such, self-loops and
+/// entries of the lower triangular part are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> ndstruct::Result<()> {
+/// use ndstruct::{csl::CslArray, graph::triangle_count};
+/// // Two triangles that share the edge 1 - 2: {0, 1, 2} and {1, 2, 3}
+/// let csl = CslArray::new(
+///   [4, 4],
+///   [1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+///   [1, 2, 0, 2, 3, 0, 1, 3, 1, 2],
+///   [0, 2, 5, 8, 10],
+/// )?;
+/// assert_eq!(triangle_count(&csl)?, 2);
+/// # Ok(()) }
+/// ```
+#[inline]
+pub fn triangle_count<DATA, DS, IS, OS>(graph: &Csl<DS, IS, OS, 2>) -> crate::Result<usize>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let vertices = vertices(graph)?;
+  let mut rslt: usize = 0;
+  for vertex in 0..vertices {
+    let vertex_indcs = neighbors(graph, vertex)?.0;
+    for neighbor in vertex_indcs.iter().copied().filter(|elem| *elem > vertex) {
+      let neighbor_indcs = neighbors(graph, neighbor)?.0;
+      let lhs = vertex_indcs.iter().copied().filter(|elem| *elem > neighbor);
+      let mut rhs = neighbor_indcs.iter().copied().filter(|elem| *elem > neighbor).peekable();
+      for elem in lhs {
+        while rhs.next_if(|rhs_elem| *rhs_elem < elem).is_some() {}
+        if rhs.next_if_eq(&elem).is_some() {
+          rslt = rslt.saturating_add(1);
+        }
+      }
+    }
+  }
+  Ok(rslt)
+}
+
+// Heap element whose ordering is reversed to make `BinaryHeap` a min-heap.
+struct MinDistance<T>(T, usize);
+
+impl<T> Eq for MinDistance<T> where T: PartialOrd {}
+
+impl<T> Ord for MinDistance<T>
+where
+  T: PartialOrd,
+{
+  #[inline]
+  fn cmp(&self, other: &Self) -> Ordering {
+    other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal).then_with(|| other.1.cmp(&self.1))
+  }
+}
+
+impl<T> PartialEq for MinDistance<T>
+where
+  T: PartialOrd,
+{
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl<T> PartialOrd for MinDistance<T>
+where
+  T: PartialOrd,
+{
+  #[inline]
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[cfg(feature = "rayon")]
+fn atomic(slice: &[AtomicUsize], idx: usize) -> crate::Result<&AtomicUsize> {
+  slice.get(idx).ok_or(crate::Error::UnknownError)
+}
+
+fn get<T>(slice: &[T], idx: usize) -> crate::Result<T>
+where
+  T: Copy,
+{
+  slice.get(idx).copied().ok_or(crate::Error::UnknownError)
+}
+
+// Column indices of all outgoing edges of `vertex`.
+#[cfg(feature = "rayon")]
+fn line_indcs<'indcs>(
+  indcs: &'indcs [usize],
+  offs: &[usize],
+  vertex: usize,
+) -> crate::Result<&'indcs [usize]> {
+  indcs.get(line_range(offs, vertex)?).ok_or(crate::Error::UnknownError)
+}
+
+// Storage range of all outgoing edges of `vertex`.
+fn line_range(offs: &[usize], vertex: usize) -> crate::Result<Range<usize>> {
+  let opt = || {
+    let first = *offs.first()?;
+    let start = offs.get(vertex)?.checked_sub(first)?;
+    let end = offs.get(vertex.checked_add(1)?)?.checked_sub(first)?;
+    Some(start..end)
+  };
+  opt().ok_or(crate::Error::UnknownError)
+}
+
+// Column indices and weights of all outgoing edges of `vertex`.
+fn neighbors<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+  vertex: usize,
+) -> crate::Result<(&[usize], &[DATA])>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let range = line_range(graph.offs(), vertex)?;
+  let opt = || Some((graph.indcs().get(range.clone())?, graph.data().get(range)?));
+  opt().ok_or(crate::Error::UnknownError)
+}
+
+// Same as `root` but shared between threads. Parents are never greater than their children,
+// as such, relaxed operations are enough to always reach a root.
+#[cfg(feature = "rayon")]
+fn par_root(parents: &[AtomicUsize], mut vertex: usize) -> crate::Result<usize> {
+  loop {
+    let parent = atomic(parents, vertex)?.load(AtomicOrdering::Relaxed);
+    if parent == vertex {
+      return Ok(vertex);
+    }
+    let grandparent = atomic(parents, parent)?.load(AtomicOrdering::Relaxed);
+    let _rslt = atomic(parents, vertex)?.compare_exchange(
+      parent,
+      grandparent,
+      AtomicOrdering::Relaxed,
+      AtomicOrdering::Relaxed,
+    );
+    vertex = grandparent;
+  }
+}
+
+// Merges the sets that contain `a` and `b` by linking the greater root to the smaller root. Retries
+// whenever another thread links the greater root first.
+#[cfg(feature = "rayon")]
+fn par_union(parents: &[AtomicUsize], a: usize, b: usize) -> crate::Result<()> {
+  loop {
+    let a_root = par_root(parents, a)?;
+    let b_root = par_root(parents, b)?;
+    let (child, parent) = match a_root.cmp(&b_root) {
+      Ordering::Equal => return Ok(()),
+      Ordering::Greater => (a_root, b_root),
+      Ordering::Less => (b_root, a_root),
+    };
+    let rslt = atomic(parents, child)?.compare_exchange(
+      child,
+      parent,
+      AtomicOrdering::Relaxed,
+      AtomicOrdering::Relaxed,
+    );
+    if rslt.is_ok() {
+      return Ok(());
+    }
+  }
+}
+
+// Representative of the set that contains `vertex` using path halving.
+fn root(parents: &mut [usize], mut vertex: usize) -> crate::Result<usize> {
+  loop {
+    let parent = get(parents, vertex)?;
+    if parent == vertex {
+      return Ok(vertex);
+    }
+    let grandparent = get(parents, parent)?;
+    set(parents, vertex, grandparent)?;
+    vertex = grandparent;
+  }
+}
+
+fn set<T>(slice: &mut [T], idx: usize, value: T) -> crate::Result<()> {
+  *slice.get_mut(idx).ok_or(crate::Error::UnknownError)? = value;
+  Ok(())
+}
+
+// Number of vertices of a square adjacency matrix.
+fn vertices<DATA, DS, IS, OS>(graph: &Csl<DS, IS, OS, 2>) -> crate::Result<usize>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let [rows, cols] = *graph.dims();
+  if rows != cols {
+    return Err(CslError::NonSquareMatrix.into());
+  }
+  Ok(rows)
+}
+
+// Same as `vertices` but also verifies that `vertex` belongs to the graph.
+fn vertices_with<DATA, DS, IS, OS>(
+  graph: &Csl<DS, IS, OS, 2>,
+  vertex: usize,
+) -> crate::Result<usize>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  let rslt = vertices(graph)?;
+  if vertex >= rslt {
+    return Err(GraphError::InvalidVertex.into());
+  }
+  Ok(rslt)
+}
@@ -0,0 +1,43 @@
+use core::fmt::{Debug, Display, Formatter};
+
+/// Any error related to graph algorithms
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum GraphError {
+  /// The damping factor of `PageRank` must be within `[0, 1]`
+  ///
+  /// ```rust
+  /// use ndstruct::{doc_tests::csl_array_4, graph::{page_rank, GraphError}};
+  /// let csl = ndstruct::csl::CslArray::new([1, 1], [1], [0], [0, 1]).unwrap();
+  /// let rslt = page_rank(&csl, 1.5, 100, 1e-6);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Graph(GraphError::InvalidDampingFactor)));
+  /// ```
+  InvalidDampingFactor,
+
+  /// The provided vertex is greater or equal than the number of vertices
+  ///
+  /// ```rust
+  /// use ndstruct::graph::{bfs, GraphError};
+  /// let csl = ndstruct::csl::CslArray::new([1, 1], [1], [0], [0, 1]).unwrap();
+  /// assert_eq!(bfs(&csl, 1), Err(ndstruct::Error::Graph(GraphError::InvalidVertex)));
+  /// ```
+  InvalidVertex,
+
+  /// Shortest paths can't be computed with negative weights
+  ///
+  /// ```rust
+  /// use ndstruct::graph::{dijkstra, GraphError};
+  /// let csl = ndstruct::csl::CslArray::new([2, 2], [-1], [1], [0, 1, 1]).unwrap();
+  /// assert_eq!(dijkstra(&csl, 0), Err(ndstruct::Error::Graph(GraphError::NegativeWeight)));
+  /// ```
+  NegativeWeight,
+}
+
+impl Display for GraphError {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+    Debug::fmt(self, f)
+  }
+}
+
+impl core::error::Error for GraphError {}
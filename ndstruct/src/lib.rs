@@ -7,16 +7,29 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
 pub mod coo;
 pub mod csl;
 pub mod dense;
+mod display;
 pub mod doc_tests;
 mod error;
+#[cfg(feature = "graph")]
+pub mod graph;
+pub mod num;
+#[cfg(feature = "simd")]
+pub mod simd;
+#[cfg(feature = "std")]
+pub mod text;
+mod traits;
 mod utils;
 
 /// Shorcut of [`core::result::Result<T, error::Error>`].
 pub type Result<T> = core::result::Result<T, Error>;
 
+pub use display::*;
 pub use error::*;
+pub use traits::*;
 #[cfg(feature = "rayon")]
 pub use utils::{ParallelIteratorWrapper, ParallelProducerWrapper};
@@ -0,0 +1,466 @@
+//! Numeric traits
+//!
+//! `no_std` abstractions over the elements of sparse and dense algebra operations. Implemented
+//! for all primitive integers and floats as well as for [`Complex`] with the `complex` feature.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub};
+#[cfg(feature = "complex")]
+use core::ops::{Neg, SubAssign};
+
+/// Absolute value or magnitude.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::num::Abs;
+/// assert_eq!((-3i8).abs_value(), 3u8);
+/// assert_eq!(i8::MIN.abs_value(), 128u8);
+/// assert_eq!((-1.5f64).abs_value(), 1.5);
+/// ```
+pub trait Abs {
+  /// Resulting type, which can differ from `Self`, e.g., the magnitude of a signed integer or
+  /// of a complex number.
+  type Output;
+
+  /// See [`Abs`].
+  fn abs_value(&self) -> Self::Output;
+}
+
+/// Complex conjugate. Real numbers are their own conjugates.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::num::Conjugate;
+/// assert_eq!(2i32.conj(), 2);
+/// assert_eq!((-1.5f32).conj(), -1.5);
+/// ```
+pub trait Conjugate {
+  /// See [`Conjugate`].
+  #[must_use]
+  fn conj(&self) -> Self;
+}
+
+/// Multiplicative identity.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::num::One;
+/// assert_eq!(u16::one(), 1);
+/// assert_eq!(f64::one(), 1.0);
+/// ```
+pub trait One: Sized {
+  /// See [`One`].
+  fn one() -> Self;
+}
+
+/// Square root. Only implemented for `f32` and `f64` with the `std` or `libm` features.
+///
+/// # Example
+#[cfg_attr(any(feature = "libm", feature = "std"), doc = "```rust")]
+#[cfg_attr(not(any(feature = "libm", feature = "std")), doc = "```ignore")]
+/// use ndstruct::num::Sqrt;
+/// assert_eq!(9.0f64.sqrt_value(), 3.0);
+/// ```
+pub trait Sqrt {
+  /// See [`Sqrt`].
+  #[must_use]
+  fn sqrt_value(&self) -> Self;
+}
+
+/// Additive identity.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::num::Zero;
+/// assert_eq!(i64::zero(), 0);
+/// assert!(0.0f32.is_zero());
+/// assert!(!1usize.is_zero());
+/// ```
+pub trait Zero: Sized {
+  /// If `self` is equal to the additive identity.
+  fn is_zero(&self) -> bool;
+
+  /// See [`Zero`].
+  fn zero() -> Self;
+}
+
+/// All the operations required by the algebra methods of this crate.
+///
+/// Automatically implemented for every type that satisfies the bounds.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::num::Num;
+/// fn sum<T: Num>(slice: &[T]) -> T {
+///   slice.iter().fold(T::zero(), |acc, elem| acc + *elem)
+/// }
+/// assert_eq!(sum(&[1u8, 2, 3]), 6);
+/// ```
+pub trait Num:
+  Add<Output = Self>
+  + AddAssign
+  + Conjugate
+  + Copy
+  + Mul<Output = Self>
+  + MulAssign
+  + One
+  + Sub<Output = Self>
+  + Zero
+{
+}
+
+impl<T> Num for T where
+  T: Add<Output = T>
+    + AddAssign
+    + Conjugate
+    + Copy
+    + Mul<Output = T>
+    + MulAssign
+    + One
+    + Sub<Output = T>
+    + Zero
+{
+}
+
+/// Complex number formed by a real and an imaginary part.
+///
+/// # Example
+///
+#[cfg_attr(feature = "complex", doc = "```rust")]
+#[cfg_attr(not(feature = "complex"), doc = "```ignore")]
+/// use ndstruct::num::{Complex, Conjugate};
+/// let a = Complex::new(1, 2);
+/// let b = Complex::new(3, -1);
+/// assert_eq!(a + b, Complex::new(4, 1));
+/// assert_eq!(a * b, Complex::new(5, 5));
+/// assert_eq!(a * a.conj(), Complex::new(5, 0));
+/// ```
+#[cfg(feature = "complex")]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Complex<T> {
+  /// Real part
+  pub re: T,
+  /// Imaginary part
+  pub im: T,
+}
+
+#[cfg(feature = "complex")]
+impl<T> Complex<T> {
+  /// Creates a new instance from its real and imaginary parts.
+  #[inline]
+  pub const fn new(re: T, im: T) -> Self {
+    Self { re, im }
+  }
+}
+
+#[cfg(feature = "complex")]
+impl<T> Complex<T>
+where
+  T: Add<Output = T> + Copy + Mul<Output = T>,
+{
+  /// Squared magnitude, i.e., `re * re + im * im`, which doesn't require a square root.
+  ///
+  /// # Example
+  ///
+  #[cfg_attr(feature = "complex", doc = "```rust")]
+  #[cfg_attr(not(feature = "complex"), doc = "```ignore")]
+  /// use ndstruct::num::Complex;
+  /// assert_eq!(Complex::new(3, -4).norm_sqr(), 25);
+  /// ```
+  #[expect(
+    clippy::arithmetic_side_effects,
+    reason = "Overflows are handled according to the semantics of the element type"
+  )]
+  #[inline]
+  pub fn norm_sqr(&self) -> T {
+    self.re * self.re + self.im * self.im
+  }
+}
+
+#[cfg(all(feature = "complex", any(feature = "libm", feature = "std")))]
+impl Abs for Complex<f32> {
+  type Output = f32;
+
+  #[inline]
+  fn abs_value(&self) -> Self::Output {
+    #[cfg(feature = "std")]
+    return self.re.hypot(self.im);
+    #[cfg(not(feature = "std"))]
+    return libm::hypotf(self.re, self.im);
+  }
+}
+
+/// ```rust
+/// use ndstruct::num::{Abs, Complex};
+/// assert_eq!(Complex::new(3.0f64, -4.0).abs_value(), 5.0);
+/// ```
+#[cfg(all(feature = "complex", any(feature = "libm", feature = "std")))]
+impl Abs for Complex<f64> {
+  type Output = f64;
+
+  #[inline]
+  fn abs_value(&self) -> Self::Output {
+    #[cfg(feature = "std")]
+    return self.re.hypot(self.im);
+    #[cfg(not(feature = "std"))]
+    return libm::hypot(self.re, self.im);
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> Add for Complex<T>
+where
+  T: Add<Output = T>,
+{
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self) -> Self::Output {
+    Self { re: self.re + rhs.re, im: self.im + rhs.im }
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> AddAssign for Complex<T>
+where
+  T: AddAssign,
+{
+  #[inline]
+  fn add_assign(&mut self, rhs: Self) {
+    self.re += rhs.re;
+    self.im += rhs.im;
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> Conjugate for Complex<T>
+where
+  T: Copy + Neg<Output = T>,
+{
+  #[inline]
+  fn conj(&self) -> Self {
+    Self { re: self.re, im: -self.im }
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> Mul for Complex<T>
+where
+  T: Add<Output = T> + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+  type Output = Self;
+
+  #[inline]
+  fn mul(self, rhs: Self) -> Self::Output {
+    Self { re: self.re * rhs.re - self.im * rhs.im, im: self.re * rhs.im + self.im * rhs.re }
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> MulAssign for Complex<T>
+where
+  T: Add<Output = T> + Copy + Mul<Output = T> + Sub<Output = T>,
+{
+  #[inline]
+  fn mul_assign(&mut self, rhs: Self) {
+    *self = *self * rhs;
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> Neg for Complex<T>
+where
+  T: Neg<Output = T>,
+{
+  type Output = Self;
+
+  #[inline]
+  fn neg(self) -> Self::Output {
+    Self { re: -self.re, im: -self.im }
+  }
+}
+
+#[cfg(feature = "complex")]
+impl<T> One for Complex<T>
+where
+  T: One + Zero,
+{
+  #[inline]
+  fn one() -> Self {
+    Self { re: T::one(), im: T::zero() }
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> Sub for Complex<T>
+where
+  T: Sub<Output = T>,
+{
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self) -> Self::Output {
+    Self { re: self.re - rhs.re, im: self.im - rhs.im }
+  }
+}
+
+#[cfg(feature = "complex")]
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+impl<T> SubAssign for Complex<T>
+where
+  T: SubAssign,
+{
+  #[inline]
+  fn sub_assign(&mut self, rhs: Self) {
+    self.re -= rhs.re;
+    self.im -= rhs.im;
+  }
+}
+
+#[cfg(feature = "complex")]
+impl<T> Zero for Complex<T>
+where
+  T: Zero,
+{
+  #[inline]
+  fn is_zero(&self) -> bool {
+    self.re.is_zero() && self.im.is_zero()
+  }
+
+  #[inline]
+  fn zero() -> Self {
+    Self { re: T::zero(), im: T::zero() }
+  }
+}
+
+macro_rules! impl_float {
+  ($($ty:ty => $sign_mask:literal, $libm_sqrt:ident),*) => {
+    $(
+      impl Abs for $ty {
+        type Output = $ty;
+
+        #[inline]
+        fn abs_value(&self) -> Self::Output {
+          <$ty>::from_bits(self.to_bits() & !$sign_mask)
+        }
+      }
+
+      impl_real!($ty, 0.0, 1.0);
+
+      #[cfg(any(feature = "libm", feature = "std"))]
+      impl Sqrt for $ty {
+        #[inline]
+        fn sqrt_value(&self) -> Self {
+          #[cfg(feature = "std")]
+          return self.sqrt();
+          #[cfg(not(feature = "std"))]
+          return libm::$libm_sqrt(*self);
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! impl_real {
+  ($ty:ty, $zero:literal, $one:literal) => {
+    impl Conjugate for $ty {
+      #[inline]
+      fn conj(&self) -> Self {
+        *self
+      }
+    }
+
+    impl One for $ty {
+      #[inline]
+      fn one() -> Self {
+        $one
+      }
+    }
+
+    impl Zero for $ty {
+      #[inline]
+      fn is_zero(&self) -> bool {
+        *self == $zero
+      }
+
+      #[inline]
+      fn zero() -> Self {
+        $zero
+      }
+    }
+  };
+}
+
+macro_rules! impl_signed {
+  ($($ty:ty => $unsigned:ty),*) => {
+    $(
+      impl Abs for $ty {
+        type Output = $unsigned;
+
+        #[inline]
+        fn abs_value(&self) -> Self::Output {
+          self.unsigned_abs()
+        }
+      }
+
+      impl_real!($ty, 0, 1);
+    )*
+  };
+}
+
+macro_rules! impl_unsigned {
+  ($($ty:ty),*) => {
+    $(
+      impl Abs for $ty {
+        type Output = $ty;
+
+        #[inline]
+        fn abs_value(&self) -> Self::Output {
+          *self
+        }
+      }
+
+      impl_real!($ty, 0, 1);
+    )*
+  };
+}
+
+impl_float!(f32 => 0x8000_0000, sqrtf, f64 => 0x8000_0000_0000_0000, sqrt);
+impl_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
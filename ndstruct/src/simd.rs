@@ -0,0 +1,411 @@
+//! SIMD
+//!
+//! Vectorized kernels for `f32` and `f64` used by the `simd_*` methods of [`Dense`] and
+//! [`Csl`].
+//!
+//! On `x86_64`, AVX2 is used when available and SSE2 otherwise. AVX2 is detected at runtime
+//! with the `std` feature or at compile-time through `target_feature` without it. Other
+//! architectures use a portable scalar fallback.
+//!
+//! Results can differ from sequential sums in the last bits because additions are performed
+//! in a different order.
+//!
+//! [`Csl`]: crate::csl::Csl
+//! [`Dense`]: crate::dense::Dense
+
+use core::ops::{Add, Mul, Sub};
+
+/// Element-wise operations applied by [`SimdFloat::zip_assign`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ZipOp {
+  /// `a + b`
+  Add,
+  /// `a * b`
+  Mul,
+  /// `a - b`
+  Sub,
+}
+
+/// Floating-point types that have vectorized kernels, i.e., `f32` and `f64`.
+pub trait SimdFloat:
+  Add<Output = Self> + Copy + Default + Mul<Output = Self> + Sub<Output = Self>
+{
+  /// Dot product of the common prefix of `a` and `b`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::simd::SimdFloat;
+  /// let a: Vec<f64> = (0..19).map(f64::from).collect();
+  /// let scalar: f64 = a.iter().map(|elem| elem * elem).sum();
+  /// assert_eq!(f64::dot(&a, &a), scalar);
+  /// assert_eq!(f32::dot(&[1.0, 2.0, 3.0], &[4.0, 5.0]), 14.0);
+  /// ```
+  fn dot(a: &[Self], b: &[Self]) -> Self;
+
+  /// Dot product between the sparse vector formed by `data` and `indcs` and the dense vector
+  /// `x`. Indices that are out of the bounds of `x` are treated as zeros.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::simd::SimdFloat;
+  /// let x: Vec<f32> = (0..40).map(|elem| f32::from(elem as u8)).collect();
+  /// let indcs: Vec<usize> = (0..13).map(|elem| elem * 3).collect();
+  /// let data: Vec<f32> = (0..13).map(|elem| f32::from(elem as u8)).collect();
+  /// let scalar: f32 = data.iter().zip(&indcs).map(|(value, idx)| value * x[*idx]).sum();
+  /// assert_eq!(f32::sparse_dot(&data, &indcs, &x), scalar);
+  /// ```
+  fn sparse_dot(data: &[Self], indcs: &[usize], x: &[Self]) -> Self;
+
+  /// Applies `op` to every element of the common prefix of `a` and `b`, storing the results
+  /// in `a`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::simd::{SimdFloat, ZipOp};
+  /// let mut a: Vec<f64> = (0..11).map(f64::from).collect();
+  /// let b: Vec<f64> = (0..11).map(|elem| f64::from(elem) * 2.0).collect();
+  /// let scalar: Vec<f64> = a.iter().zip(&b).map(|(lhs, rhs)| lhs - rhs).collect();
+  /// f64::zip_assign(&mut a, &b, ZipOp::Sub);
+  /// assert_eq!(a, scalar);
+  /// ```
+  fn zip_assign(a: &mut [Self], b: &[Self], op: ZipOp);
+}
+
+// Portable kernels, also used for the elements that don't fill a whole vector.
+#[expect(
+  clippy::arithmetic_side_effects,
+  reason = "Overflows are handled according to the semantics of the element type"
+)]
+mod scalar {
+  use crate::simd::ZipOp;
+  use core::ops::{Add, Mul, Sub};
+
+  pub(crate) fn dot<T>(a: &[T], b: &[T]) -> T
+  where
+    T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+  {
+    a.iter().zip(b).fold(T::default(), |acc, (lhs, rhs)| acc + *lhs * *rhs)
+  }
+
+  pub(crate) fn sparse_dot<T>(data: &[T], indcs: &[usize], x: &[T]) -> T
+  where
+    T: Add<Output = T> + Copy + Default + Mul<Output = T>,
+  {
+    data.iter().zip(indcs).fold(T::default(), |acc, (value, idx)| {
+      acc + *value * x.get(*idx).copied().unwrap_or_default()
+    })
+  }
+
+  pub(crate) fn zip_assign<T>(a: &mut [T], b: &[T], op: ZipOp)
+  where
+    T: Add<Output = T> + Copy + Mul<Output = T> + Sub<Output = T>,
+  {
+    for (lhs, rhs) in a.iter_mut().zip(b) {
+      *lhs = match op {
+        ZipOp::Add => *lhs + *rhs,
+        ZipOp::Mul => *lhs * *rhs,
+        ZipOp::Sub => *lhs - *rhs,
+      };
+    }
+  }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+mod fallback {
+  use crate::simd::{scalar, SimdFloat, ZipOp};
+
+  macro_rules! impl_simd_float {
+    ($ty:ident) => {
+      impl SimdFloat for $ty {
+        #[inline]
+        fn dot(a: &[Self], b: &[Self]) -> Self {
+          scalar::dot(a, b)
+        }
+
+        #[inline]
+        fn sparse_dot(data: &[Self], indcs: &[usize], x: &[Self]) -> Self {
+          scalar::sparse_dot(data, indcs, x)
+        }
+
+        #[inline]
+        fn zip_assign(a: &mut [Self], b: &[Self], op: ZipOp) {
+          scalar::zip_assign(a, b, op);
+        }
+      }
+    };
+  }
+
+  impl_simd_float!(f32);
+  impl_simd_float!(f64);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[expect(unsafe_code, reason = "Intrinsics of `core::arch` are unsafe")]
+mod x86_64 {
+  use crate::simd::{SimdFloat, ZipOp};
+
+  macro_rules! impl_isa {
+    (
+      $isa:ident,
+      $feature:literal,
+      $ty:ident,
+      $vector:ident,
+      $lanes:literal,
+      $setzero:ident,
+      $loadu:ident,
+      $storeu:ident,
+      $add:ident,
+      $mul:ident,
+      $sub:ident
+    ) => {
+      #[expect(
+        clippy::float_arithmetic,
+        reason = "Partial sums are combined with scalar operations"
+      )]
+      mod $isa {
+        use crate::simd::{scalar, ZipOp};
+        use core::arch::x86_64::*;
+
+        const LANES: usize = $lanes;
+
+        // SAFETY: Callers must ensure that the CPU supports the enabled target feature.
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn dot(a: &[$ty], b: &[$ty]) -> $ty {
+          let len = a.len().min(b.len());
+          let (a, b) = (a.get(..len).unwrap_or_default(), b.get(..len).unwrap_or_default());
+          let mut a_chunks = a.chunks_exact(LANES);
+          let mut b_chunks = b.chunks_exact(LANES);
+          let mut acc = $setzero();
+          for (a_chunk, b_chunk) in a_chunks.by_ref().zip(b_chunks.by_ref()) {
+            // SAFETY: The chunk has `LANES` elements
+            let lhs = unsafe { $loadu(a_chunk.as_ptr()) };
+            // SAFETY: The chunk has `LANES` elements
+            let rhs = unsafe { $loadu(b_chunk.as_ptr()) };
+            acc = $add(acc, $mul(lhs, rhs));
+          }
+          horizontal_sum(acc) + scalar::dot(a_chunks.remainder(), b_chunks.remainder())
+        }
+
+        // SAFETY: Callers must ensure that the CPU supports the enabled target feature.
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn sparse_dot(data: &[$ty], indcs: &[usize], x: &[$ty]) -> $ty {
+          let len = data.len().min(indcs.len());
+          let data = data.get(..len).unwrap_or_default();
+          let indcs = indcs.get(..len).unwrap_or_default();
+          let mut data_chunks = data.chunks_exact(LANES);
+          let mut indcs_chunks = indcs.chunks_exact(LANES);
+          let mut acc = $setzero();
+          let mut buffer = [0.0; LANES];
+          for (data_chunk, indcs_chunk) in data_chunks.by_ref().zip(indcs_chunks.by_ref()) {
+            for (elem, idx) in buffer.iter_mut().zip(indcs_chunk) {
+              *elem = x.get(*idx).copied().unwrap_or_default();
+            }
+            // SAFETY: The chunk has `LANES` elements
+            let lhs = unsafe { $loadu(data_chunk.as_ptr()) };
+            // SAFETY: The buffer has `LANES` elements
+            let rhs = unsafe { $loadu(buffer.as_ptr()) };
+            acc = $add(acc, $mul(lhs, rhs));
+          }
+          let remainder = scalar::sparse_dot(data_chunks.remainder(), indcs_chunks.remainder(), x);
+          horizontal_sum(acc) + remainder
+        }
+
+        // SAFETY: Callers must ensure that the CPU supports the enabled target feature.
+        #[target_feature(enable = $feature)]
+        pub(crate) unsafe fn zip_assign(a: &mut [$ty], b: &[$ty], op: ZipOp) {
+          let len = a.len().min(b.len());
+          let (a, b) = (a.get_mut(..len).unwrap_or_default(), b.get(..len).unwrap_or_default());
+          let mut a_chunks = a.chunks_exact_mut(LANES);
+          let mut b_chunks = b.chunks_exact(LANES);
+          for (a_chunk, b_chunk) in a_chunks.by_ref().zip(b_chunks.by_ref()) {
+            // SAFETY: The chunk has `LANES` elements
+            let lhs = unsafe { $loadu(a_chunk.as_ptr()) };
+            // SAFETY: The chunk has `LANES` elements
+            let rhs = unsafe { $loadu(b_chunk.as_ptr()) };
+            let rslt = match op {
+              ZipOp::Add => $add(lhs, rhs),
+              ZipOp::Mul => $mul(lhs, rhs),
+              ZipOp::Sub => $sub(lhs, rhs),
+            };
+            // SAFETY: The chunk has `LANES` elements
+            unsafe { $storeu(a_chunk.as_mut_ptr(), rslt) };
+          }
+          scalar::zip_assign(a_chunks.into_remainder(), b_chunks.remainder(), op);
+        }
+
+        #[target_feature(enable = $feature)]
+        unsafe fn horizontal_sum(vector: $vector) -> $ty {
+          let mut buffer = [0.0; LANES];
+          // SAFETY: The buffer has `LANES` elements
+          unsafe { $storeu(buffer.as_mut_ptr(), vector) };
+          buffer.iter().fold(0.0, |acc, elem| acc + elem)
+        }
+      }
+    };
+  }
+
+  macro_rules! impl_simd_float {
+    ($ty:ident, $avx2:ident, $sse2:ident) => {
+      impl SimdFloat for $ty {
+        #[inline]
+        fn dot(a: &[Self], b: &[Self]) -> Self {
+          if has_avx2() {
+            // SAFETY: AVX2 is supported
+            unsafe { $avx2::dot(a, b) }
+          } else {
+            // SAFETY: SSE2 is part of the `x86_64` baseline
+            unsafe { $sse2::dot(a, b) }
+          }
+        }
+
+        #[inline]
+        fn sparse_dot(data: &[Self], indcs: &[usize], x: &[Self]) -> Self {
+          if has_avx2() {
+            // SAFETY: AVX2 is supported
+            unsafe { $avx2::sparse_dot(data, indcs, x) }
+          } else {
+            // SAFETY: SSE2 is part of the `x86_64` baseline
+            unsafe { $sse2::sparse_dot(data, indcs, x) }
+          }
+        }
+
+        #[inline]
+        fn zip_assign(a: &mut [Self], b: &[Self], op: ZipOp) {
+          if has_avx2() {
+            // SAFETY: AVX2 is supported
+            unsafe { $avx2::zip_assign(a, b, op) }
+          } else {
+            // SAFETY: SSE2 is part of the `x86_64` baseline
+            unsafe { $sse2::zip_assign(a, b, op) }
+          }
+        }
+      }
+    };
+  }
+
+  impl_isa!(
+    avx2_f32,
+    "avx2",
+    f32,
+    __m256,
+    8,
+    _mm256_setzero_ps,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_add_ps,
+    _mm256_mul_ps,
+    _mm256_sub_ps
+  );
+  impl_isa!(
+    avx2_f64,
+    "avx2",
+    f64,
+    __m256d,
+    4,
+    _mm256_setzero_pd,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_add_pd,
+    _mm256_mul_pd,
+    _mm256_sub_pd
+  );
+  impl_isa!(
+    sse2_f32,
+    "sse2",
+    f32,
+    __m128,
+    4,
+    _mm_setzero_ps,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_add_ps,
+    _mm_mul_ps,
+    _mm_sub_ps
+  );
+  impl_isa!(
+    sse2_f64,
+    "sse2",
+    f64,
+    __m128d,
+    2,
+    _mm_setzero_pd,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_add_pd,
+    _mm_mul_pd,
+    _mm_sub_pd
+  );
+
+  impl_simd_float!(f32, avx2_f32, sse2_f32);
+  impl_simd_float!(f64, avx2_f64, sse2_f64);
+
+  fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    return std::is_x86_feature_detected!("avx2");
+    #[cfg(not(feature = "std"))]
+    return cfg!(target_feature = "avx2");
+  }
+
+  // Each instruction set is compared against the scalar kernels regardless of the instruction set
+  // selected at runtime. Elements are small integers, which makes all results exact.
+  #[cfg(test)]
+  #[expect(
+    clippy::float_cmp,
+    reason = "Elements are small integers, which makes all results exact"
+  )]
+  mod tests {
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+
+    use crate::simd::{scalar, ZipOp};
+
+    // Greater than twice the number of lanes of all vectors, which covers all remainders.
+    const LEN: usize = 21;
+    const X_LEN: usize = 50;
+
+    macro_rules! test_isa {
+      ($test:ident, $isa:ident, $ty:ident, $feature:tt) => {
+        #[test]
+        fn $test() {
+          if !std::is_x86_feature_detected!($feature) {
+            return;
+          }
+          let elem = |idx: usize, modulus: usize| {
+            $ty::from(u8::try_from(idx.wrapping_rem(modulus)).unwrap_or_default())
+          };
+          let a: [$ty; LEN] = core::array::from_fn(|idx| elem(idx, 7));
+          let b: [$ty; LEN] = core::array::from_fn(|idx| elem(idx, 5));
+          // Some indices are out of the bounds of `x`
+          let indcs: [usize; LEN] = core::array::from_fn(|idx| idx.wrapping_mul(3));
+          let x: [$ty; X_LEN] = core::array::from_fn(|idx| elem(idx, 11));
+          for len in 0..=LEN {
+            let lhs = a.get(..len).unwrap_or_default();
+            let rhs = b.get(..len).unwrap_or_default();
+            let sparse_indcs = indcs.get(..len).unwrap_or_default();
+            // SAFETY: The target feature is supported
+            let dot = unsafe { super::$isa::dot(lhs, rhs) };
+            assert_eq!(dot, scalar::dot(lhs, rhs));
+            // SAFETY: The target feature is supported
+            let sparse_dot = unsafe { super::$isa::sparse_dot(lhs, sparse_indcs, &x) };
+            assert_eq!(sparse_dot, scalar::sparse_dot(lhs, sparse_indcs, &x));
+            for op in [ZipOp::Add, ZipOp::Mul, ZipOp::Sub] {
+              let [mut simd, mut expected] = [a; 2];
+              // SAFETY: The target feature is supported
+              unsafe { super::$isa::zip_assign(simd.get_mut(..len).unwrap_or_default(), rhs, op) };
+              scalar::zip_assign(expected.get_mut(..len).unwrap_or_default(), rhs, op);
+              assert_eq!(simd, expected);
+            }
+          }
+        }
+      };
+    }
+
+    test_isa!(avx2_f32, avx2_f32, f32, "avx2");
+    test_isa!(avx2_f64, avx2_f64, f64, "avx2");
+    test_isa!(sse2_f32, sse2_f32, f32, "sse2");
+    test_isa!(sse2_f64, sse2_f64, f64, "sse2");
+  }
+}
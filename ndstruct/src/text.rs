@@ -0,0 +1,394 @@
+//! Text formats
+//!
+//! Readers and writers of sparse structures stored as delimited text where each line contains the
+//! indices of all dimensions followed by the value, e.g., FROSTT `.tns` files or CSV/TSV
+//! triplets.
+//!
+//! Files are read line by line through [`TripletIter`], which means that elements can be
+//! consumed without loading the whole file into memory.
+
+mod text_error;
+
+use crate::{
+  coo::{Coo, CooVec},
+  csl::{Csl, CslError, CslVec},
+  utils::windows2,
+};
+use alloc::{string::String, vec::Vec};
+use cl_aux::SingleTypeStorage;
+use core::{fmt::Display, marker::PhantomData, str::FromStr};
+use std::io::{BufRead, Write};
+pub use text_error::*;
+
+/// Layout of a delimited text file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TextFormat {
+  /// Lines that start with this character, ignoring leading whitespace, are skipped. Blank lines
+  /// are always skipped.
+  pub comment: Option<char>,
+  /// Separator of fields. `None` means any non-empty sequence of whitespace.
+  pub delimiter: Option<char>,
+  /// Value of the first index of each dimension.
+  pub index_base: usize,
+}
+
+impl TextFormat {
+  /// Comma-separated zero-based triplets.
+  pub const CSV: Self = Self { comment: Some('#'), delimiter: Some(','), index_base: 0 };
+  /// FROSTT `.tns` files, i.e., whitespace-separated one-based coordinates.
+  pub const TNS: Self = Self { comment: Some('#'), delimiter: None, index_base: 1 };
+  /// Tab-separated zero-based triplets.
+  pub const TSV: Self = Self { comment: Some('#'), delimiter: Some('\t'), index_base: 0 };
+}
+
+/// Iterator that lazily parses the elements of a delimited text file, one line at a time.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::text::{TextFormat, TripletIter};
+/// let file = &b"# i j k value\n1 1 2 1.5\n\n2 3 1 -2\n"[..];
+/// let mut iter = TripletIter::<f64, _, 3>::new(file, TextFormat::TNS);
+/// assert_eq!(iter.next(), Some(Ok(([0, 0, 1], 1.5))));
+/// assert_eq!(iter.next(), Some(Ok(([1, 2, 0], -2.0))));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug)]
+pub struct TripletIter<DATA, R, const D: usize> {
+  buffer: String,
+  format: TextFormat,
+  line: usize,
+  phantom: PhantomData<DATA>,
+  reader: R,
+}
+
+impl<DATA, R, const D: usize> TripletIter<DATA, R, D> {
+  /// Creates a new instance that reads lines from `reader`.
+  ///
+  /// # Arguments
+  ///
+  /// * `reader`: Source of the file
+  /// * `format`: Layout of the file
+  #[inline]
+  pub fn new(reader: R, format: TextFormat) -> Self {
+    Self { buffer: String::new(), format, line: 0, phantom: PhantomData, reader }
+  }
+}
+
+impl<DATA, R, const D: usize> Iterator for TripletIter<DATA, R, D>
+where
+  DATA: FromStr,
+  R: BufRead,
+{
+  type Item = crate::Result<([usize; D], DATA)>;
+
+  #[inline]
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      self.buffer.clear();
+      match self.reader.read_line(&mut self.buffer) {
+        Ok(0) => return None,
+        Ok(_) => {}
+        Err(err) => return Some(Err(TextError::Io(err.kind()).into())),
+      }
+      self.line = self.line.saturating_add(1);
+      let content = self.buffer.trim();
+      if content.is_empty() || self.format.comment.is_some_and(|elem| content.starts_with(elem)) {
+        continue;
+      }
+      return Some(parse_line(content, self.format, self.line));
+    }
+  }
+}
+
+impl<DATA, DS, const D: usize> Coo<DS, D>
+where
+  DS: AsRef<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = ([usize; D], DATA)>,
+{
+  /// Writes all stored elements, one per line, according to `format`.
+  ///
+  /// # Arguments
+  ///
+  /// * `writer`: Destination of the file
+  /// * `format`: Layout of the file
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooArray, text::TextFormat};
+  /// let coo = CooArray::new([2, 3], [([0, 2], 1.5), ([1, 0], 2.0)]).unwrap();
+  /// let mut file = Vec::new();
+  /// coo.write_text(&mut file, TextFormat::TNS).unwrap();
+  /// assert_eq!(file, b"1 3 1.5\n2 1 2\n");
+  /// ```
+  #[inline]
+  pub fn write_text<W>(&self, writer: W, format: TextFormat) -> crate::Result<()>
+  where
+    DATA: Display,
+    W: Write,
+  {
+    write_triplets(writer, format, self.iter_nnz())
+  }
+}
+
+impl<DATA, const D: usize> CooVec<DATA, D> {
+  /// Reads all elements of a delimited text file. Elements don't need to be sorted.
+  ///
+  /// If `dims` is `None`, then each dimension is inferred as the greatest respective index
+  /// plus one. Violations related to a single element, e.g., repeated indices, are returned as
+  /// [`crate::Error::CooAt`] along with the location of the offending element.
+  ///
+  /// # Arguments
+  ///
+  /// * `reader`: Source of the file
+  /// * `format`: Layout of the file
+  /// * `dims`: Array of dimensions, if known
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooVec, text::TextFormat};
+  /// let file = &b"% Comment\n1,2,3.0\n0,0,1.0\n"[..];
+  /// let format = TextFormat { comment: Some('%'), ..TextFormat::CSV };
+  /// let coo = CooVec::<f64, 2>::read_text(file, format, None).unwrap();
+  /// assert_eq!(coo.dims(), &[2, 3]);
+  /// assert_eq!(coo.data(), &[([0, 0], 1.0), ([1, 2], 3.0)]);
+  ///
+  /// let err = CooVec::<f64, 2>::read_text(&b"1,2,3.0\n1,2,4.0\n"[..], format, None).unwrap_err();
+  /// assert_eq!(err.location().map(|location| location.pos), Some(1));
+  /// ```
+  #[inline]
+  pub fn read_text<R>(
+    reader: R,
+    format: TextFormat,
+    dims: Option<[usize; D]>,
+  ) -> crate::Result<Self>
+  where
+    DATA: FromStr,
+    R: BufRead,
+  {
+    let mut data = TripletIter::new(reader, format).collect::<crate::Result<Vec<_>>>()?;
+    sort_elems(&mut data)?;
+    let final_dims = if let Some(elem) = dims {
+      elem
+    } else {
+      let mut inferred = [0; D];
+      for (indcs, _) in &data {
+        for (dim, idx) in inferred.iter_mut().zip(indcs) {
+          *dim = (*dim).max(idx.saturating_add(1));
+        }
+      }
+      inferred
+    };
+    Coo::new_located(final_dims, data)
+  }
+}
+
+impl<DATA, DS, IS, OS, const D: usize> Csl<DS, IS, OS, D>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  /// Writes all stored elements, one per line, according to `format`.
+  ///
+  /// # Arguments
+  ///
+  /// * `writer`: Destination of the file
+  /// * `format`: Layout of the file
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::CslArray, text::TextFormat};
+  /// let csl = CslArray::new([2, 3], [1, 2, 3], [0, 2, 1], [0, 2, 3]).unwrap();
+  /// let mut file = Vec::new();
+  /// csl.write_text(&mut file, TextFormat::TSV).unwrap();
+  /// assert_eq!(file, b"0\t0\t1\n0\t2\t2\n1\t1\t3\n");
+  /// ```
+  #[inline]
+  pub fn write_text<W>(&self, writer: W, format: TextFormat) -> crate::Result<()>
+  where
+    DATA: Display,
+    W: Write,
+  {
+    write_triplets(writer, format, self.iter_nnz())
+  }
+}
+
+impl<DATA, const D: usize> CslVec<DATA, D> {
+  /// Creates a valid instance from elements of any order, e.g., the elements of a
+  /// [`TripletIter`], without creating an intermediary COO.
+  ///
+  /// Elements are placed into their lines by counting the number of elements of each line, which
+  /// means that only the elements of each line are sorted. Every element that shares the indices
+  /// of a previous element is merged into the latter by `dup_policy`. See
+  /// [`CslVec::from_raw_unsorted`].
+  ///
+  /// # Arguments
+  ///
+  /// * `dims`: Array of dimensions
+  /// * `triplets`: Indices and value of each element
+  /// * `dup_policy`: Callback that merges duplicated elements
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{csl::CslVec, text::{TextFormat, TripletIter}};
+  /// let file = &b"2 1 5\n1 3 1\n1 1 2\n2 1 1\n"[..];
+  /// let triplets = TripletIter::new(file, TextFormat::TNS);
+  /// let csl = CslVec::from_triplets([2, 3], triplets, |prev, dup| *prev += dup).unwrap();
+  /// assert_eq!(csl, CslVec::new([2, 3], vec![2, 1, 6], vec![0, 2, 0], vec![0, 2, 3]).unwrap());
+  /// ```
+  #[inline]
+  pub fn from_triplets(
+    dims: [usize; D],
+    triplets: impl IntoIterator<Item = crate::Result<([usize; D], DATA)>>,
+    dup_policy: impl FnMut(&mut DATA, DATA),
+  ) -> crate::Result<Self> {
+    let (outermost_dims, innermost_dim) = match dims.split_last() {
+      Some((last, rest)) => (rest, *last),
+      None => return Err(CslError::InnermostDimsZero.into()),
+    };
+    let lines = outermost_dims
+      .iter()
+      .try_fold(1, |acc: usize, dim| acc.checked_mul(*dim))
+      .ok_or(crate::Error::InsufficientCapacity)?;
+    let mut offs: Vec<usize> = alloc::vec![0; lines.saturating_add(1)];
+    let mut elems = Vec::new();
+    let mut elems_lines = Vec::new();
+    for triplet in triplets {
+      let (indcs, elem) = triplet?;
+      let (line_indcs, idx) = match indcs.split_last() {
+        Some((last, rest)) if *last < innermost_dim => (rest, *last),
+        _ => return Err(CslError::IndcsGreaterThanEqualDimLength.into()),
+      };
+      let mut line: usize = 0;
+      for (line_idx, dim) in line_indcs.iter().zip(outermost_dims) {
+        if line_idx >= dim {
+          return Err(CslError::IndcsGreaterThanEqualDimLength.into());
+        }
+        line = line.wrapping_mul(*dim).wrapping_add(*line_idx);
+      }
+      let count = offs.get_mut(line.wrapping_add(1)).ok_or(crate::Error::UnknownError)?;
+      *count = count.wrapping_add(1);
+      elems.push((idx, elem));
+      elems_lines.push(line);
+    }
+    let mut acc: usize = 0;
+    for off in &mut offs {
+      acc = acc.wrapping_add(*off);
+      *off = acc;
+    }
+    place_by_line(&mut elems, &mut elems_lines, &offs)?;
+    let (indcs, data) = elems.into_iter().unzip();
+    Self::from_raw_unsorted(dims, data, indcs, offs, dup_policy)
+  }
+}
+
+// Trimmed fields of a line. Whitespace delimiters never produce empty fields.
+fn fields(content: &str, delimiter: Option<char>) -> impl Iterator<Item = &str> {
+  content
+    .split(move |elem: char| delimiter.map_or(elem.is_whitespace(), |local| elem == local))
+    .map(str::trim)
+    .filter(move |elem| delimiter.is_some() || !elem.is_empty())
+}
+
+fn parse_line<DATA, const D: usize>(
+  content: &str,
+  format: TextFormat,
+  line: usize,
+) -> crate::Result<([usize; D], DATA)>
+where
+  DATA: FromStr,
+{
+  let invalid_field = || TextError::InvalidField { line };
+  let mut iter = fields(content, format.delimiter);
+  let mut indcs = [0; D];
+  for idx in &mut indcs {
+    let value: usize = iter.next().and_then(|elem| elem.parse().ok()).ok_or_else(invalid_field)?;
+    *idx = value.checked_sub(format.index_base).ok_or(TextError::IndexBelowBase { line })?;
+  }
+  let data = iter.next().and_then(|elem| elem.parse().ok()).ok_or_else(invalid_field)?;
+  if iter.next().is_some() {
+    return Err(invalid_field().into());
+  }
+  Ok((indcs, data))
+}
+
+// Moves every element to the next free position of its line, which preserves the reading order
+// within each line. `offs` contains the starting position of each line and `lines` is overwritten
+// by the final positions.
+fn place_by_line<T>(elems: &mut [T], lines: &mut [usize], offs: &[usize]) -> crate::Result<()> {
+  let mut next_positions = offs.to_vec();
+  for line in lines.iter_mut() {
+    let next_position = next_positions.get_mut(*line).ok_or(crate::Error::UnknownError)?;
+    *line = *next_position;
+    *next_position = next_position.wrapping_add(1);
+  }
+  // Follows the cycles of the permutation where the element located at `pos` must be moved to
+  // `lines[pos]`. Each swap puts at least one element in its final position.
+  for pos in 0..lines.len() {
+    while let Some(dest) = lines.get(pos).copied().filter(|dest| *dest != pos) {
+      if dest >= lines.len() || dest >= elems.len() {
+        return Err(crate::Error::UnknownError);
+      }
+      elems.swap(pos, dest);
+      lines.swap(pos, dest);
+    }
+  }
+  Ok(())
+}
+
+// Sorts elements by their indices. Elements are placed by counting their outermost indices and
+// then only sorted within each outermost index, unless the outermost indices are sparser than the
+// elements themselves.
+fn sort_elems<DATA, const D: usize>(elems: &mut [([usize; D], DATA)]) -> crate::Result<()> {
+  let first_idx = |indcs: &[usize; D]| indcs.first().copied().unwrap_or(0);
+  let buckets =
+    elems.iter().map(|elem| first_idx(&elem.0)).max().map_or(0, |idx| idx.saturating_add(1));
+  if buckets > elems.len() {
+    elems.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    return Ok(());
+  }
+  let mut offs: Vec<usize> = alloc::vec![0; buckets.saturating_add(1)];
+  let mut positions = Vec::with_capacity(elems.len());
+  for (indcs, _) in elems.iter() {
+    let idx = first_idx(indcs);
+    let count = offs.get_mut(idx.wrapping_add(1)).ok_or(crate::Error::UnknownError)?;
+    *count = count.wrapping_add(1);
+    positions.push(idx);
+  }
+  let mut acc: usize = 0;
+  for off in &mut offs {
+    acc = acc.wrapping_add(*off);
+    *off = acc;
+  }
+  place_by_line(elems, &mut positions, &offs)?;
+  for [a, b] in windows2(&offs) {
+    elems.get_mut(*a..*b).ok_or(crate::Error::UnknownError)?.sort_unstable_by(|c, d| c.0.cmp(&d.0));
+  }
+  Ok(())
+}
+
+fn write_triplets<'data, DATA, W, const D: usize>(
+  mut writer: W,
+  format: TextFormat,
+  triplets: impl Iterator<Item = ([usize; D], &'data DATA)>,
+) -> crate::Result<()>
+where
+  DATA: Display + 'data,
+  W: Write,
+{
+  let delimiter = format.delimiter.unwrap_or(' ');
+  let io_err = |err: std::io::Error| TextError::Io(err.kind());
+  for (indcs, elem) in triplets {
+    for idx in indcs {
+      let based = idx.checked_add(format.index_base).ok_or(crate::Error::InsufficientCapacity)?;
+      write!(writer, "{based}{delimiter}").map_err(io_err)?;
+    }
+    writeln!(writer, "{elem}").map_err(io_err)?;
+  }
+  writer.flush().map_err(io_err)?;
+  Ok(())
+}
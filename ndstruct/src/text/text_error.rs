@@ -0,0 +1,51 @@
+use core::fmt::{Debug, Display, Formatter};
+use std::io::ErrorKind;
+
+/// Any error related to text formats
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TextError {
+  /// An index is smaller than the index base of the format
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooVec, text::{TextError, TextFormat}};
+  /// let rslt = CooVec::<f64, 2>::read_text(&b"1 1 2.0\n0 1 3.0\n"[..], TextFormat::TNS, None);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Text(TextError::IndexBelowBase { line: 2 })));
+  /// ```
+  IndexBelowBase {
+    /// One-based number of the offending line
+    line: usize,
+  },
+
+  /// A field can't be parsed or the number of fields isn't the number of dimensions plus one
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooVec, text::{TextError, TextFormat}};
+  /// let rslt = CooVec::<f64, 2>::read_text(&b"# Comment\n0,1\n"[..], TextFormat::CSV, None);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Text(TextError::InvalidField { line: 2 })));
+  /// ```
+  InvalidField {
+    /// One-based number of the offending line
+    line: usize,
+  },
+
+  /// Reading or writing failed
+  ///
+  /// ```rust
+  /// use ndstruct::{coo::CooArray, text::{TextError, TextFormat}};
+  /// use std::io::ErrorKind;
+  /// let coo = CooArray::new([2, 2], [([0, 0], 1.0), ([1, 1], 2.0)]).unwrap();
+  /// let rslt = coo.write_text(&mut [0; 4][..], TextFormat::CSV);
+  /// assert_eq!(rslt, Err(ndstruct::Error::Text(TextError::Io(ErrorKind::WriteZero))));
+  /// ```
+  Io(ErrorKind),
+}
+
+impl Display for TextError {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+    Debug::fmt(self, f)
+  }
+}
+
+impl core::error::Error for TextError {}
@@ -0,0 +1,316 @@
+#[cfg(feature = "alloc")]
+use crate::utils::add_axis_nnz;
+use crate::{coo::Coo, csl::Csl, dense::Dense, utils::max_nnz, NdDisplay, NdSpy};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use cl_aux::SingleTypeStorage;
+
+/// Common behavior shared by all N-dimensional structures of this crate, allowing algorithms
+/// to be written once regardless of the underlying format.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::{
+///   doc_tests::{coo_array_5, csl_array_4, dense_array_3},
+///   NdStruct,
+/// };
+/// fn sum<const D: usize>(elem: &impl NdStruct<D, Data = i32>) -> i32 {
+///   elem.iter_nnz().map(|(_, value)| *value).sum()
+/// }
+/// assert_eq!(sum(&coo_array_5()), 28);
+/// assert_eq!(sum(&csl_array_4()), 45);
+/// assert_eq!(sum(&dense_array_3()), 666);
+/// ```
+pub trait NdStruct<const D: usize> {
+  /// Stored element
+  type Data;
+
+  /// The definitions of all dimensions.
+  fn dims(&self) -> &[usize; D];
+
+  /// See [`NdDisplay`] for more information.
+  #[inline]
+  fn display(&self) -> NdDisplay<'_, Self, D>
+  where
+    Self: Sized,
+  {
+    NdDisplay::new(self)
+  }
+
+  /// Iterator that returns all stored elements in storage order alongside the indices of all
+  /// dimensions.
+  fn iter_nnz<'this>(&'this self) -> impl Iterator<Item = ([usize; D], &'this Self::Data)>
+  where
+    Self::Data: 'this;
+
+  /// Number of stored elements.
+  fn nnz(&self) -> usize;
+
+  /// See [`NdSpy`] for more information.
+  #[inline]
+  fn spy(&self) -> NdSpy<'_, Self, D>
+  where
+    Self: Sized,
+  {
+    NdSpy::new(self)
+  }
+
+  /// If any, retrieves an immutable data reference of a given set of indices.
+  fn value(&self, indcs: [usize; D]) -> Option<&Self::Data>;
+}
+
+/// Mutable counterpart of [`NdStruct`].
+pub trait NdStructMut<const D: usize>: NdStruct<D> {
+  /// Iterator that returns all stored elements in storage order alongside the indices of all
+  /// dimensions.
+  fn iter_nnz_mut<'this>(
+    &'this mut self,
+  ) -> impl Iterator<Item = ([usize; D], &'this mut Self::Data)>
+  where
+    Self::Data: 'this;
+
+  /// If any, retrieves a mutable data reference of a given set of indices.
+  fn value_mut(&mut self, indcs: [usize; D]) -> Option<&mut Self::Data>;
+}
+
+/// Structures that only store non-zero elements, i.e., any location without a stored element is
+/// implicitly zero.
+///
+/// # Example
+///
+/// ```rust
+/// use ndstruct::{doc_tests::csl_array_4, SparseTensor};
+/// let csl = csl_array_4();
+/// assert_eq!(csl.max_nnz(), 120);
+/// assert!(csl.is_nnz([1, 0, 2, 2]));
+/// assert!(!csl.is_nnz([1, 0, 2, 3]));
+/// ```
+pub trait SparseTensor<const D: usize>: NdStruct<D> {
+  /// Number of stored elements of each index along the dimension located at `axis`. Returns
+  /// `None` if `axis` is out of bounds.
+  ///
+  /// # Example
+  #[cfg_attr(feature = "alloc", doc = "```rust")]
+  #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+  /// use ndstruct::{doc_tests::{coo_array_5, csl_array_4}, SparseTensor};
+  /// assert_eq!(coo_array_5().axis_nnz(0), Some(vec![4, 3]));
+  /// assert_eq!(csl_array_4().axis_nnz(3), Some(vec![1, 1, 3, 2, 2]));
+  /// assert_eq!(csl_array_4().axis_nnz(4), None);
+  /// ```
+  #[cfg(feature = "alloc")]
+  #[inline]
+  fn axis_nnz(&self, axis: usize) -> Option<Vec<usize>> {
+    let mut rslt = alloc::vec![0; *self.dims().get(axis)?];
+    for (indcs, _) in self.iter_nnz() {
+      add_axis_nnz(&mut rslt, &indcs, axis);
+    }
+    Some(rslt)
+  }
+
+  /// Ratio between the number of stored elements and the maximum number of elements.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ndstruct::{doc_tests::csl_array_4, SparseTensor};
+  /// assert_eq!(csl_array_4().density(), 0.075);
+  /// ```
+  #[expect(
+    clippy::as_conversions,
+    clippy::cast_precision_loss,
+    clippy::float_arithmetic,
+    reason = "A ratio is inherently imprecise"
+  )]
+  #[inline]
+  fn density(&self) -> f64 {
+    let max_nnz = self.max_nnz();
+    if max_nnz == 0 {
+      return 0.0;
+    }
+    self.nnz() as f64 / max_nnz as f64
+  }
+
+  /// If the location defined by `indcs` has a stored element.
+  #[inline]
+  fn is_nnz(&self, indcs: [usize; D]) -> bool {
+    self.value(indcs).is_some()
+  }
+
+  /// Maximum number of elements that can be stored according to the current dimensions.
+  #[inline]
+  fn max_nnz(&self) -> usize {
+    max_nnz(self.dims())
+  }
+}
+
+impl<DATA, DS, const D: usize> NdStruct<D> for Coo<DS, D>
+where
+  DS: AsRef<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = ([usize; D], DATA)>,
+{
+  type Data = DATA;
+
+  #[inline]
+  fn dims(&self) -> &[usize; D] {
+    self.dims()
+  }
+
+  #[inline]
+  fn iter_nnz<'this>(&'this self) -> impl Iterator<Item = ([usize; D], &'this Self::Data)>
+  where
+    Self::Data: 'this,
+  {
+    self.iter_nnz()
+  }
+
+  #[inline]
+  fn nnz(&self) -> usize {
+    self.data().len()
+  }
+
+  #[inline]
+  fn value(&self, indcs: [usize; D]) -> Option<&Self::Data> {
+    self.value(indcs)
+  }
+}
+
+impl<DATA, DS, const D: usize> NdStructMut<D> for Coo<DS, D>
+where
+  DS: AsMut<[<DS as SingleTypeStorage>::Item]>
+    + AsRef<[<DS as SingleTypeStorage>::Item]>
+    + SingleTypeStorage<Item = ([usize; D], DATA)>,
+{
+  #[inline]
+  fn iter_nnz_mut<'this>(
+    &'this mut self,
+  ) -> impl Iterator<Item = ([usize; D], &'this mut Self::Data)>
+  where
+    Self::Data: 'this,
+  {
+    self.iter_nnz_mut()
+  }
+
+  #[inline]
+  fn value_mut(&mut self, indcs: [usize; D]) -> Option<&mut Self::Data> {
+    self.value_mut(indcs)
+  }
+}
+
+impl<DATA, DS, const D: usize> SparseTensor<D> for Coo<DS, D> where
+  DS: AsRef<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = ([usize; D], DATA)>
+{
+}
+
+impl<DATA, DS, IS, OS, const D: usize> NdStruct<D> for Csl<DS, IS, OS, D>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  type Data = DATA;
+
+  #[inline]
+  fn dims(&self) -> &[usize; D] {
+    self.dims()
+  }
+
+  #[inline]
+  fn iter_nnz<'this>(&'this self) -> impl Iterator<Item = ([usize; D], &'this Self::Data)>
+  where
+    Self::Data: 'this,
+  {
+    self.iter_nnz()
+  }
+
+  #[inline]
+  fn nnz(&self) -> usize {
+    self.nnz()
+  }
+
+  #[inline]
+  fn value(&self, indcs: [usize; D]) -> Option<&Self::Data> {
+    self.value(indcs)
+  }
+}
+
+impl<DATA, DS, IS, OS, const D: usize> NdStructMut<D> for Csl<DS, IS, OS, D>
+where
+  DS: AsMut<[DATA]> + AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+  #[inline]
+  fn iter_nnz_mut<'this>(
+    &'this mut self,
+  ) -> impl Iterator<Item = ([usize; D], &'this mut Self::Data)>
+  where
+    Self::Data: 'this,
+  {
+    self.iter_nnz_mut()
+  }
+
+  #[inline]
+  fn value_mut(&mut self, indcs: [usize; D]) -> Option<&mut Self::Data> {
+    self.value_mut(indcs)
+  }
+}
+
+impl<DATA, DS, IS, OS, const D: usize> SparseTensor<D> for Csl<DS, IS, OS, D>
+where
+  DS: AsRef<[DATA]> + SingleTypeStorage<Item = DATA>,
+  IS: AsRef<[usize]>,
+  OS: AsRef<[usize]>,
+{
+}
+
+impl<DATA, DS, const D: usize> NdStruct<D> for Dense<DS, D>
+where
+  DS: AsRef<[<DS as SingleTypeStorage>::Item]> + SingleTypeStorage<Item = DATA>,
+{
+  type Data = DATA;
+
+  #[inline]
+  fn dims(&self) -> &[usize; D] {
+    self.dims()
+  }
+
+  #[inline]
+  fn iter_nnz<'this>(&'this self) -> impl Iterator<Item = ([usize; D], &'this Self::Data)>
+  where
+    Self::Data: 'this,
+  {
+    self.indexed_iter()
+  }
+
+  #[inline]
+  fn nnz(&self) -> usize {
+    self.data().len()
+  }
+
+  #[inline]
+  fn value(&self, indcs: [usize; D]) -> Option<&Self::Data> {
+    self.value(indcs)
+  }
+}
+
+impl<DATA, DS, const D: usize> NdStructMut<D> for Dense<DS, D>
+where
+  DS: AsMut<[<DS as SingleTypeStorage>::Item]>
+    + AsRef<[<DS as SingleTypeStorage>::Item]>
+    + SingleTypeStorage<Item = DATA>,
+{
+  #[inline]
+  fn iter_nnz_mut<'this>(
+    &'this mut self,
+  ) -> impl Iterator<Item = ([usize; D], &'this mut Self::Data)>
+  where
+    Self::Data: 'this,
+  {
+    self.indexed_iter_mut()
+  }
+
+  #[inline]
+  fn value_mut(&mut self, indcs: [usize; D]) -> Option<&mut Self::Data> {
+    self.value_mut(indcs)
+  }
+}
@@ -1,4 +1,13 @@
+#[cfg(all(feature = "alloc", feature = "rayon"))]
+use alloc::vec::Vec;
 use cl_aux::ArrayWrapper;
+use core::num::NonZeroUsize;
+#[cfg(all(feature = "alloc", feature = "rand", feature = "rayon"))]
+use core::ops::Range;
+
+// Maximum number of chunks of the outermost dimension used by parallel random generators.
+#[cfg(all(feature = "alloc", feature = "rand", feature = "rayon"))]
+const PAR_RANDOM_CHUNKS: usize = 1024;
 
 #[cfg(feature = "rayon")]
 /// Parallel iterator for Rayon implementation. This is mostly an internal detail.
@@ -10,6 +19,15 @@ pub struct ParallelIteratorWrapper<I>(pub(crate) I);
 #[derive(Debug)]
 pub struct ParallelProducerWrapper<I>(pub(crate) I);
 
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn add_axis_nnz<const D: usize>(rslt: &mut [usize], indcs: &[usize; D], axis: usize) {
+  if let Some(elem) = indcs.get(axis).and_then(|idx| rslt.get_mut(*idx)) {
+    *elem = elem.saturating_add(1);
+  }
+}
+
+#[cfg(feature = "alloc")]
 #[inline]
 pub(crate) fn are_in_ascending_order<'slice, T, U>(
   slice: &'slice [T],
@@ -26,26 +44,55 @@ where
 }
 
 #[inline]
-pub(crate) fn are_in_upper_bound<T>(slice: &[T], upper_bound: &T) -> bool
-where
-  T: PartialOrd,
-{
-  slice.iter().all(|x| x < upper_bound)
+pub(crate) fn dims_product<const D: usize>(dims: &[usize; D]) -> Option<usize> {
+  dims.iter().try_fold(1usize, |acc, dim| acc.checked_mul(*dim))
 }
 
+// Number of elements described by `dims` where zero dimensions are ignored, i.e., treated as one.
 #[inline]
-pub(crate) fn has_duplicates<T>(slice: &[T]) -> bool
+pub(crate) fn nonzero_dims_product(dims: &[usize]) -> Option<usize> {
+  dims.iter().filter(|dim| **dim != 0).try_fold(1usize, |acc, dim| acc.checked_mul(*dim))
+}
+
+// Positions of the first element that is equal to a previous element and of that previous
+// element.
+#[inline]
+pub(crate) fn duplicated_pos<T>(slice: &[T]) -> Option<[usize; 2]>
 where
   T: PartialEq,
 {
-  for (a_idx, a) in slice.iter().enumerate() {
-    for b in slice.iter().skip(a_idx.saturating_add(1)) {
-      if a == b {
-        return true;
-      }
+  slice.iter().enumerate().find_map(|(b_idx, b)| {
+    let a_idx = slice.get(..b_idx)?.iter().position(|a| a == b)?;
+    Some([a_idx, b_idx])
+  })
+}
+
+// Row-major linear index of the element located at `indcs`. Zero dimensions are ignored, i.e.,
+// treated as one.
+#[inline]
+pub(crate) fn indcs_to_linear_idx<const D: usize>(
+  dims: &[usize; D],
+  indcs: &[usize; D],
+) -> Option<usize> {
+  let mut idx: usize = 0;
+  for (elem, dim) in indcs.iter().zip(dims).filter(|(_, dim)| **dim != 0) {
+    idx = idx.checked_mul(*dim)?.checked_add(*elem)?;
+  }
+  Some(idx)
+}
+
+// Row-major indices of the element located at `idx` of a fully filled structure. Zero
+// dimensions are ignored, i.e., treated as one, so their indices are always zero.
+#[inline]
+pub(crate) fn linear_idx_to_indcs<const D: usize>(dims: &[usize; D], mut idx: usize) -> [usize; D] {
+  let mut indcs = [0; D];
+  for (elem, dim) in indcs.iter_mut().zip(dims).rev() {
+    if let Some(nonzero_dim) = NonZeroUsize::new(*dim) {
+      *elem = idx % nonzero_dim;
+      idx /= nonzero_dim;
     }
   }
-  false
+  indcs
 }
 
 #[inline]
@@ -92,6 +139,73 @@ where
   dims
 }
 
+// Splits `outermost_dim` into contiguous ranges, each one paired with its share of `nnz`. Both the
+// ranges and the shares only depend on the arguments, which makes the output of parallel random
+// generators independent of the number of threads.
+#[cfg(all(feature = "alloc", feature = "rand", feature = "rayon"))]
+#[inline]
+pub(crate) fn par_random_chunks(
+  outermost_dim: usize,
+  nnz: usize,
+) -> Option<Vec<(Range<usize>, usize)>> {
+  let chunk_len = outermost_dim.div_ceil(PAR_RANDOM_CHUNKS).max(1);
+  let base = nnz.checked_div(outermost_dim)?;
+  let remainder = nnz.checked_rem(outermost_dim)?;
+  (0..outermost_dim)
+    .step_by(chunk_len)
+    .map(|start| {
+      let end = start.saturating_add(chunk_len).min(outermost_dim);
+      let len = end.checked_sub(start)?;
+      let chunk_nnz =
+        base.checked_mul(len)?.checked_add(remainder.saturating_sub(start).min(len))?;
+      Some((start..end, chunk_nnz))
+    })
+    .collect()
+}
+
+#[cfg(all(feature = "alloc", feature = "rayon"))]
+#[inline]
+pub(crate) fn par_axis_nnz<'any, T, const D: usize>(
+  iter: impl rayon::iter::ParallelIterator<Item = ([usize; D], &'any T)>,
+  dims: &[usize; D],
+  axis: usize,
+) -> Option<Vec<usize>>
+where
+  T: Sync + 'any,
+{
+  use rayon::iter::ParallelIterator;
+  let len = *dims.get(axis)?;
+  let rslt = iter
+    .fold(
+      || alloc::vec![0; len],
+      |mut rslt, (indcs, _)| {
+        add_axis_nnz(&mut rslt, &indcs, axis);
+        rslt
+      },
+    )
+    .reduce(
+      || alloc::vec![0; len],
+      |mut a, b| {
+        for (a_elem, b_elem) in a.iter_mut().zip(b) {
+          *a_elem = a_elem.saturating_add(b_elem);
+        }
+        a
+      },
+    );
+  Some(rslt)
+}
+
+// Seed of the independent random stream `stream` derived from `seed` using `SplitMix64`.
+#[cfg(all(feature = "alloc", feature = "rand", feature = "rayon"))]
+#[inline]
+pub(crate) fn stream_seed(seed: u64, stream: usize) -> u64 {
+  let stream_u64 = u64::try_from(stream).unwrap_or(u64::MAX).wrapping_add(1);
+  let mut rslt = seed.wrapping_add(stream_u64.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+  rslt = (rslt ^ rslt.wrapping_shr(30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  rslt = (rslt ^ rslt.wrapping_shr(27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+  rslt ^ rslt.wrapping_shr(31)
+}
+
 #[inline]
 pub(crate) fn windows2<T>(slice: &[T]) -> impl Iterator<Item = [&T; 2]> {
   slice.windows(2).filter_map(|value| Some([value.first()?, value.get(1)?]))